@@ -0,0 +1,63 @@
+use core::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+
+use goofy_animals::{DEFAULT_GOOFY_ANIMALS, GoofyAnimals};
+
+const TWO_ADJECTIVES: GoofyAnimals<'static> = GoofyAnimals::new(&["cat", "dog"], &["big", "red"]);
+const THREE_ADJECTIVES: GoofyAnimals<'static> =
+    GoofyAnimals::new(&["cat", "dog"], &["big", "red", "shy"]);
+
+/// The rejection loop `generate_name_parts` used before switching to exact sampling.
+fn rejection_loop<'a>(
+    animals: &GoofyAnimals<'a>,
+    rng: &mut impl Rng,
+) -> (&'a str, &'a str, &'a str) {
+    let adjectives = animals.get_adjectives();
+    let (adjective_one, adjective_two) = loop {
+        let one = rng.random_range(0..adjectives.len());
+        let two = rng.random_range(0..adjectives.len());
+
+        if one == two {
+            continue;
+        }
+
+        break (one, two);
+    };
+
+    let animals = animals.get_animals();
+    let animal = rng.random_range(0..animals.len());
+
+    (
+        adjectives.get(adjective_one).unwrap(),
+        adjectives.get(adjective_two).unwrap(),
+        animals.get(animal).unwrap(),
+    )
+}
+
+fn generate_name_parts(c: &mut Criterion) {
+    for (label, animals) in [
+        ("default", DEFAULT_GOOFY_ANIMALS),
+        ("two adjectives", TWO_ADJECTIVES),
+        ("three adjectives", THREE_ADJECTIVES),
+    ] {
+        let mut group = c.benchmark_group(label);
+
+        group.bench_function("exact", |b| {
+            let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
+            b.iter(|| black_box(animals.generate_name_parts(&mut rng)))
+        });
+
+        group.bench_function("rejection loop", |b| {
+            let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
+            b.iter(|| black_box(rejection_loop(&animals, &mut rng)))
+        });
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, generate_name_parts);
+criterion_main!(benches);
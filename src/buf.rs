@@ -0,0 +1,202 @@
+use core::fmt::{Debug, Display, Formatter, Write};
+use core::ops::Deref;
+
+/// A fixed-capacity, stack-allocated string for rendering names without `alloc`.
+///
+/// `GoofyNameBuf` implements [`core::fmt::Write`], so it can be passed to
+/// [`GoofyAnimals::write_name`](crate::GoofyAnimals::write_name) or used with
+/// `write!`. Writes are all or nothing: when a name or any other formatted text
+/// doesn't fit in the remaining capacity, the buffer is left unchanged, so the
+/// contents are always valid UTF-8 and never hold part of a name.
+///
+/// Use [`GoofyAnimals::max_name_len`](crate::GoofyAnimals::max_name_len) to size the
+/// buffer at compile time.
+///
+/// # Examples
+///
+/// ```rust
+/// use rand::SeedableRng;
+/// use rand_chacha::ChaCha20Rng;
+/// use goofy_animals::{DEFAULT_GOOFY_ANIMALS, GoofyNameBuf};
+///
+/// const MAX_LEN: usize = DEFAULT_GOOFY_ANIMALS.max_name_len();
+///
+/// let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
+/// let mut name = GoofyNameBuf::<MAX_LEN>::new();
+/// DEFAULT_GOOFY_ANIMALS.write_name(&mut name, &mut rng).unwrap();
+/// assert_eq!(name.as_str(), "dismal-outlying-moth");
+/// ```
+#[derive(Clone, Copy)]
+pub struct GoofyNameBuf<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> GoofyNameBuf<N> {
+    /// Creates a new, empty buffer.
+    pub const fn new() -> Self {
+        Self {
+            bytes: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the contents of the buffer.
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).expect("only whole strings are written")
+    }
+
+    /// Returns the length of the contents in bytes.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if nothing has been written to the buffer.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of bytes the buffer can hold.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Empties the buffer, so it can be reused for another name.
+    pub const fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl<const N: usize> Default for GoofyNameBuf<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Write for GoofyNameBuf<N> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        let target = self.bytes.get_mut(self.len..end).ok_or(core::fmt::Error)?;
+
+        target.copy_from_slice(s.as_bytes());
+        self.len = end;
+
+        Ok(())
+    }
+
+    fn write_fmt(&mut self, args: core::fmt::Arguments<'_>) -> core::fmt::Result {
+        // Formatting takes many writes, drop those that fit if a later one doesn't
+        let len = self.len;
+        core::fmt::write(self, args).inspect_err(|_| self.len = len)
+    }
+}
+
+impl<const N: usize> Deref for GoofyNameBuf<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for GoofyNameBuf<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> PartialEq for GoofyNameBuf<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for GoofyNameBuf<N> {}
+
+impl<const N: usize> PartialEq<str> for GoofyNameBuf<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for GoofyNameBuf<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize> Display for GoofyNameBuf<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<const N: usize> Debug for GoofyNameBuf<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::fmt::Write;
+
+    use super::GoofyNameBuf;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn write() {
+        let mut buf = GoofyNameBuf::<8>::new();
+        assert!(buf.is_empty());
+
+        write!(buf, "moth").unwrap();
+        assert_eq!(buf, "moth");
+
+        // Doesn't fit, so nothing is written
+        assert!(write!(buf, "-moth").is_err());
+        assert_eq!(buf, "moth");
+
+        write!(buf, "-owl").unwrap();
+        assert_eq!(buf, "moth-owl");
+        assert_eq!(buf.len(), buf.capacity());
+
+        buf.clear();
+        assert_eq!(buf, "");
+    }
+
+    #[test]
+    fn partial_name() {
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha20Rng;
+
+        let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
+        let mut buf = GoofyNameBuf::<10>::new();
+        write!(buf, "id: ").unwrap();
+
+        // None of a name that doesn't fit is kept
+        assert!(
+            crate::DEFAULT_GOOFY_ANIMALS
+                .write_name(&mut buf, &mut rng)
+                .is_err()
+        );
+        assert_eq!(buf, "id: ");
+
+        let (animal, other) = ("moth", "owl");
+        assert!(write!(buf, "{animal}-{other}").is_err());
+        assert_eq!(buf, "id: ");
+
+        let animal = "ox";
+        write!(buf, "{animal}-{other}").unwrap();
+        assert_eq!(buf, "id: ox-owl");
+    }
+
+    #[test]
+    fn multi_byte() {
+        let mut buf = GoofyNameBuf::<3>::new();
+
+        write!(buf, "é").unwrap();
+        assert!(write!(buf, "é").is_err());
+        assert_eq!(buf.as_str(), "é");
+    }
+}
@@ -0,0 +1,300 @@
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+
+use ::alloc::string::String;
+use ::alloc::vec::Vec;
+
+use crate::{GoofyAnimals, MAX_ADJECTIVES, WordList};
+
+/// A reusable index completing partial names, for example for tab completion.
+///
+/// The index holds the positions of the adjectives and animals sorted by their
+/// kebab-case form, so each completion is a binary search followed by a scan of the
+/// matching words. Build it once and share it between lookups.
+///
+/// Names are completed slot by slot: the adjectives already typed in full are kept
+/// and only the word being typed is completed, so `dismal-out` completes to the
+/// names starting with `dismal` and an adjective starting with `out`. Multi-word
+/// entries such as `polar bear` are written and completed in kebab-case, like
+/// `polar-bear`. Matching is case-sensitive.
+///
+/// # Examples
+///
+/// ```rust
+/// use goofy_animals::{DEFAULT_GOOFY_ANIMALS, NameCompleter};
+///
+/// let completer = NameCompleter::new(DEFAULT_GOOFY_ANIMALS);
+///
+/// let completions: Vec<String> = completer.complete("dismal-outl").collect();
+/// assert_eq!(completions, ["dismal-outlandish", "dismal-outlying"]);
+///
+/// assert!(completer.complete("dismal-outlying-polar-b").eq(["dismal-outlying-polar-bear"]));
+/// ```
+///
+/// # Feature Flag
+///
+/// This type is only available when the `alloc` feature is enabled.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug)]
+pub struct NameCompleter<'a> {
+    animals: GoofyAnimals<'a>,
+    sorted_adjectives: Vec<usize>,
+    sorted_animals: Vec<usize>,
+}
+
+/// The completions of a partial name, in alphabetical order.
+///
+/// Created by [`NameCompleter::complete`].
+///
+/// # Feature Flag
+///
+/// This type is only available when the `alloc` feature is enabled.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug)]
+pub struct Completions {
+    names: ::alloc::vec::IntoIter<String>,
+}
+
+impl<'a> NameCompleter<'a> {
+    /// Builds the completion index of the word lists of `animals`.
+    ///
+    /// # Arguments
+    ///
+    /// * `animals` - The word lists to complete names from
+    ///
+    /// # Returns
+    ///
+    /// A new `NameCompleter` instance.
+    pub fn new(animals: GoofyAnimals<'a>) -> Self {
+        Self {
+            animals,
+            sorted_adjectives: sorted(animals.adjective_list()),
+            sorted_animals: sorted(animals.animal_list()),
+        }
+    }
+
+    /// Returns the [`GoofyAnimals`] instance names are completed from.
+    pub fn goofy_animals(&self) -> GoofyAnimals<'a> {
+        self.animals
+    }
+
+    /// Completes a partial name in `adjective-adjective-animal` form.
+    ///
+    /// # Arguments
+    ///
+    /// * `partial` - The beginning of a name
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the names, up to and including the word being typed, that
+    /// start with `partial`. Adjectives already in the name aren't suggested again.
+    pub fn complete(&self, partial: &str) -> Completions {
+        let mut names = Vec::new();
+        self.complete_slot(
+            0,
+            partial,
+            &mut String::new(),
+            &mut [0; MAX_ADJECTIVES],
+            &mut names,
+        );
+
+        // Splitting a multi-word adjective differently can lead to the same name
+        names.sort_unstable();
+        names.dedup();
+
+        Completions {
+            names: names.into_iter(),
+        }
+    }
+
+    /// Adds the completions of the word in `slot`, starting the rest of the name,
+    /// to `names`. `typed` holds the name up to `slot` and `chosen` the adjectives
+    /// in it.
+    fn complete_slot(
+        &self,
+        slot: usize,
+        rest: &str,
+        typed: &mut String,
+        chosen: &mut [usize; MAX_ADJECTIVES],
+        names: &mut Vec<String>,
+    ) {
+        let is_adjective = slot < self.animals.adjective_count();
+        let (list, sorted) = if is_adjective {
+            (self.animals.adjective_list(), &self.sorted_adjectives)
+        } else {
+            (self.animals.animal_list(), &self.sorted_animals)
+        };
+
+        let start = sorted.partition_point(|&index| kebab_cmp(list.word(index), rest).is_lt());
+        for &index in &sorted[start..] {
+            let word = list.word(index);
+            if !kebab_starts_with(word, rest) {
+                break;
+            }
+
+            if !(is_adjective && chosen[..slot].contains(&index)) {
+                names.push(::alloc::format!("{typed}{}", word.replace(' ', "-")));
+            }
+        }
+
+        if !is_adjective {
+            return;
+        }
+
+        // Every adjective typed in full and followed by a separator moves on to the next slot
+        for (end, _) in rest.match_indices('-') {
+            let word = &rest[..end];
+            let Ok(position) = sorted.binary_search_by(|&index| kebab_cmp(list.word(index), word))
+            else {
+                continue;
+            };
+
+            let index = sorted[position];
+            if chosen[..slot].contains(&index) {
+                continue;
+            }
+
+            let length = typed.len();
+            typed.push_str(word);
+            typed.push('-');
+            chosen[slot] = index;
+
+            self.complete_slot(slot + 1, &rest[end + 1..], typed, chosen, names);
+            typed.truncate(length);
+        }
+    }
+}
+
+impl Iterator for Completions {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.names.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.names.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Completions {
+    fn next_back(&mut self) -> Option<String> {
+        self.names.next_back()
+    }
+}
+
+impl ExactSizeIterator for Completions {}
+
+impl FusedIterator for Completions {}
+
+/// Returns the positions of the words of a list, sorted by their kebab-case form.
+fn sorted(list: WordList<'_>) -> Vec<usize> {
+    let mut sorted: Vec<usize> = (0..list.len()).collect();
+    sorted.sort_unstable_by(|&a, &b| kebab_bytes(list.word(a)).cmp(kebab_bytes(list.word(b))));
+    sorted
+}
+
+/// Returns the bytes of a word list entry with spaces replaced by `-`.
+fn kebab_bytes(word: &str) -> impl Iterator<Item = u8> + '_ {
+    word.bytes()
+        .map(|byte| if byte == b' ' { b'-' } else { byte })
+}
+
+/// Compares the kebab-case form of a word list entry to kebab-case text.
+fn kebab_cmp(word: &str, text: &str) -> Ordering {
+    kebab_bytes(word).cmp(text.bytes())
+}
+
+/// Checks whether the kebab-case form of a word list entry starts with `prefix`.
+fn kebab_starts_with(word: &str, prefix: &str) -> bool {
+    word.len() >= prefix.len() && kebab_bytes(word).zip(prefix.bytes()).all(|(a, b)| a == b)
+}
+
+#[cfg(test)]
+mod test {
+    use super::NameCompleter;
+    use crate::{DEFAULT_GOOFY_ANIMALS, GoofyAnimals};
+
+    use pretty_assertions::assert_eq;
+
+    const ANIMALS: GoofyAnimals<'static> = GoofyAnimals::new(
+        &["moth", "polar bear", "polar fox", "bear", "mole"],
+        &["big", "big red", "red", "shy", "bigger"],
+    );
+
+    fn complete(
+        completer: &NameCompleter<'_>,
+        partial: &str,
+    ) -> ::alloc::vec::Vec<::alloc::string::String> {
+        completer.complete(partial).collect()
+    }
+
+    #[test]
+    fn slots() {
+        let completer = NameCompleter::new(ANIMALS);
+
+        assert_eq!(
+            complete(&completer, ""),
+            ["big", "big-red", "bigger", "red", "shy"]
+        );
+        assert_eq!(complete(&completer, "big"), ["big", "big-red", "bigger"]);
+        assert_eq!(
+            complete(&completer, "big-"),
+            ["big-big-red", "big-bigger", "big-red", "big-shy"]
+        );
+        assert_eq!(
+            complete(&completer, "big-red-"),
+            [
+                "big-red-bear",
+                "big-red-big",
+                "big-red-bigger",
+                "big-red-mole",
+                "big-red-moth",
+                "big-red-polar-bear",
+                "big-red-polar-fox",
+                "big-red-red",
+                "big-red-shy",
+            ]
+        );
+        assert_eq!(
+            complete(&completer, "shy-red-polar-"),
+            ["shy-red-polar-bear", "shy-red-polar-fox"]
+        );
+        assert_eq!(
+            complete(&completer, "shy-red-mo"),
+            ["shy-red-mole", "shy-red-moth"]
+        );
+        assert_eq!(complete(&completer, "shy-red-moth"), ["shy-red-moth"]);
+
+        // Adjectives aren't repeated, and unknown words have no completions
+        assert_eq!(complete(&completer, "shy-s"), [] as [&str; 0]);
+        assert_eq!(complete(&completer, "tall-"), [] as [&str; 0]);
+        assert_eq!(complete(&completer, "shy-red-moth-"), [] as [&str; 0]);
+        assert_eq!(complete(&completer, "Shy"), [] as [&str; 0]);
+    }
+
+    #[test]
+    fn default_lists() {
+        let completer = NameCompleter::new(DEFAULT_GOOFY_ANIMALS);
+
+        assert_eq!(
+            completer.complete("").len(),
+            DEFAULT_GOOFY_ANIMALS.adjective_list().len()
+        );
+        assert_eq!(
+            completer.complete("outlying-").len(),
+            DEFAULT_GOOFY_ANIMALS.adjective_list().len() - 1
+        );
+        assert_eq!(
+            completer.complete("outlying-haunting-").len(),
+            DEFAULT_GOOFY_ANIMALS.animal_list().len()
+        );
+
+        let mut completions = completer.complete("outlying-haunting-polar-b");
+        assert_eq!(
+            completions.next().as_deref(),
+            Some("outlying-haunting-polar-bear")
+        );
+        assert_eq!(completions.next(), None);
+    }
+}
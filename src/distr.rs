@@ -0,0 +1,133 @@
+use rand::Rng;
+use rand::distr::Distribution;
+
+#[cfg(feature = "alloc")]
+use crate::NameStyle;
+use crate::{GoofyAnimals, GoofyName};
+
+/// Samples names like [`GoofyAnimals::generate_name_parts`], so word lists compose
+/// with [`Rng::sample`], [`Rng::sample_iter`] and [`Distribution::map`].
+///
+/// # Examples
+///
+/// ```rust
+/// use rand::{Rng, SeedableRng};
+/// use rand_chacha::ChaCha20Rng;
+/// use goofy_animals::DEFAULT_GOOFY_ANIMALS;
+///
+/// let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
+/// let name = rng.sample(DEFAULT_GOOFY_ANIMALS);
+/// assert_eq!(name.to_string(), "dismal-outlying-moth");
+///
+/// let names: Vec<_> = rng.sample_iter(DEFAULT_GOOFY_ANIMALS).take(3).collect();
+/// assert_eq!(names.len(), 3);
+/// ```
+///
+/// # Feature Flag
+///
+/// This implementation is only available when the `rand` feature is enabled.
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl<'a> Distribution<GoofyName<'a>> for GoofyAnimals<'a> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GoofyName<'a> {
+        self.generate_name_parts(rng)
+    }
+}
+
+/// Word lists sampling names as `String`s in a given [`NameStyle`].
+///
+/// Created by [`GoofyAnimals::styled`].
+///
+/// # Feature Flag
+///
+/// This type is only available when the `rand` and `alloc` features are enabled.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "rand", feature = "alloc"))))]
+#[derive(Clone, Copy, Debug)]
+pub struct StyledNames<'a> {
+    animals: GoofyAnimals<'a>,
+    style: NameStyle,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> GoofyAnimals<'a> {
+    /// Returns a [`Distribution`] of names formatted in the given style, like those
+    /// of [`GoofyAnimals::generate_name_styled`].
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - The casing and separator of the names
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rand::{Rng, SeedableRng};
+    /// use rand_chacha::ChaCha20Rng;
+    /// use goofy_animals::{DEFAULT_GOOFY_ANIMALS, NameStyle};
+    ///
+    /// let rng = ChaCha20Rng::seed_from_u64(0x1337);
+    /// let names: Vec<String> = rng
+    ///     .sample_iter(DEFAULT_GOOFY_ANIMALS.styled(NameStyle::Kebab))
+    ///     .take(2)
+    ///     .collect();
+    /// assert_eq!(names, ["dismal-outlying-moth", "healthy-yellowish-firefly"]);
+    /// ```
+    ///
+    /// # Feature Flag
+    ///
+    /// This function is only available when the `rand` and `alloc` features are
+    /// enabled.
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "rand", feature = "alloc"))))]
+    pub const fn styled(self, style: NameStyle) -> StyledNames<'a> {
+        StyledNames {
+            animals: self,
+            style,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Distribution<::alloc::string::String> for StyledNames<'_> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ::alloc::string::String {
+        use ::alloc::string::ToString;
+
+        self.animals
+            .generate_name_parts(rng)
+            .styled(self.style)
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha20Rng;
+
+    use crate::{DEFAULT_GOOFY_ANIMALS, NameStyle};
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn matches_generate_name() {
+        let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
+        let mut expected = ChaCha20Rng::seed_from_u64(0x1337);
+
+        for name in (&mut rng).sample_iter(DEFAULT_GOOFY_ANIMALS).take(100) {
+            assert_eq!(
+                name,
+                DEFAULT_GOOFY_ANIMALS.generate_name_parts(&mut expected)
+            );
+        }
+
+        let styled = DEFAULT_GOOFY_ANIMALS.styled(NameStyle::Title);
+        for _ in 0..100 {
+            assert_eq!(
+                rng.sample(styled),
+                DEFAULT_GOOFY_ANIMALS.generate_name_styled(&mut expected, NameStyle::Title)
+            );
+        }
+
+        let short = DEFAULT_GOOFY_ANIMALS.with_adjective_count(1);
+        let name = rng.sample(short);
+        assert_eq!(name.adjectives().len(), 1);
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{GoofyAnimals, NameStyle};
+
+/// Word lists with entries made of other entries, so that the words of a name can
+/// be read in more than one way.
+pub(crate) const ANIMALS: GoofyAnimals<'static> = GoofyAnimals::new(
+    &["moth", "polar bear", "bear", "polar"],
+    &["big", "big red", "red", "shy"],
+);
+
+/// Every style, with a custom separator.
+pub(crate) const STYLES: [NameStyle; 7] = [
+    NameStyle::Kebab,
+    NameStyle::Snake,
+    NameStyle::Camel,
+    NameStyle::Pascal,
+    NameStyle::ScreamingSnake,
+    NameStyle::Title,
+    NameStyle::Custom('.'),
+];
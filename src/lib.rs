@@ -8,34 +8,148 @@ extern crate alloc;
 
 use core::fmt::{Debug, Formatter};
 
-use rand::Rng;
+use crate::sample::{sample_index, sample_index_v1};
+
+pub use buf::GoofyNameBuf;
+#[cfg(feature = "alloc")]
+pub use complete::{Completions, NameCompleter};
+#[cfg(all(feature = "rand", feature = "alloc"))]
+pub use distr::StyledNames;
+#[cfg(feature = "std")]
+pub use load::LoadError;
+#[doc(hidden)]
+pub use macros::__private;
+pub use name::GoofyName;
+#[cfg(feature = "alloc")]
+pub use owned::GoofyAnimalsBuf;
+pub use parse::ParseNameError;
+pub use pattern::{MAX_PATTERN_SEGMENTS, NamePattern, PatternError};
+#[cfg(feature = "std")]
+pub use random::{random_name, random_name_parts};
+pub use sample::sample_below;
+#[cfg(feature = "std")]
+pub use scan::ReaderMatches;
+#[cfg(feature = "alloc")]
+pub use scan::{NameMatch, NameMatches, NameScanner};
+#[cfg(feature = "fastrand")]
+pub use source::FastRand;
+#[cfg(feature = "rand08")]
+pub use source::Rand08;
+pub use source::{RandomFn, RandomSource};
+pub use style::{NameStyle, StyledName};
+#[cfg(feature = "alloc")]
+pub use suggest::{MAX_SUGGESTION_DISTANCE, Suggestion};
+pub use unique::UniqueNameGenerator;
+pub use validation::{WordListError, WordListKind};
+pub use version::WordListVersion;
+pub use word_list::{WordList, WordListIter};
+
+mod buf;
+#[cfg(feature = "alloc")]
+mod complete;
+#[cfg(feature = "rand")]
+mod distr;
+#[cfg(test)]
+mod fixtures;
+#[cfg(feature = "std")]
+mod load;
+#[macro_use]
+mod macros;
+mod name;
+#[cfg(feature = "alloc")]
+mod owned;
+mod parse;
+mod pattern;
+#[cfg(feature = "std")]
+mod random;
+mod sample;
+#[cfg(feature = "alloc")]
+mod scan;
+mod source;
+mod style;
+#[cfg(feature = "alloc")]
+mod suggest;
+mod unique;
+mod validation;
+mod version;
+mod word_list;
 
 /// A default instance of `GoofyAnimals` initialized with the built-in English word lists.
 ///
 /// This constant provides convenient access to a pre-configured `GoofyAnimals` instance
 /// that uses the included animal and adjective lists.
-pub const DEFAULT_GOOFY_ANIMALS: GoofyAnimals<'static> = GoofyAnimals::new(
-    &const_str::split!(include_str!("data/en_animals.txt"), "\n"),
-    &const_str::split!(include_str!("data/en_adjectives.txt"), "\n"),
+pub const DEFAULT_GOOFY_ANIMALS: GoofyAnimals<'static> = builtin_goofy_animals!(
+    animals = "data/en_animals.txt",
+    adjectives = "data/en_adjectives.txt",
+);
+
+/// A `GoofyAnimals` instance initialized with a small selection of the built-in word lists.
+///
+/// The 64 animals and 256 adjectives are short, common words picked from the default
+/// lists, so every name generated by this instance is also a valid name of
+/// [`DEFAULT_GOOFY_ANIMALS`] and [`LARGE_GOOFY_ANIMALS`]. This suits targets where
+/// flash is tight, as long as the default lists aren't used elsewhere.
+///
+/// # Feature Flag
+///
+/// This constant is only available when the `small-words` feature is enabled.
+#[cfg(feature = "small-words")]
+#[cfg_attr(docsrs, doc(cfg(feature = "small-words")))]
+pub const SMALL_GOOFY_ANIMALS: GoofyAnimals<'static> = builtin_goofy_animals!(
+    animals = "data/small/en_animals.txt",
+    adjectives = "data/small/en_adjectives.txt",
+);
+
+/// A `GoofyAnimals` instance initialized with an extended version of the built-in word lists.
+///
+/// The 518 animals and 1671 adjectives include every word of the default lists, so
+/// every name generated by [`DEFAULT_GOOFY_ANIMALS`] is also a valid name of this
+/// instance, which generates about two and a half times as many distinct names.
+///
+/// # Feature Flag
+///
+/// This constant is only available when the `large-words` feature is enabled.
+#[cfg(feature = "large-words")]
+#[cfg_attr(docsrs, doc(cfg(feature = "large-words")))]
+pub const LARGE_GOOFY_ANIMALS: GoofyAnimals<'static> = builtin_goofy_animals!(
+    animals = "data/large/en_animals.txt",
+    adjectives = "data/large/en_adjectives.txt",
 );
 
+/// The largest number of adjectives a single name can have.
+pub const MAX_ADJECTIVES: usize = 4;
+
 /// A struct that manages lists of adjectives and animals for generating goofy names.
 ///
 /// `GoofyAnimals` allows you to generate random names in the format
 /// `adjective-adjective-animal` using custom word lists or the default ones.
+/// The number of adjectives per name can be changed with
+/// [`GoofyAnimals::with_adjective_count`].
+#[derive(Clone, Copy)]
 pub struct GoofyAnimals<'a> {
-    animals: &'a [&'a str],
-    adjectives: &'a [&'a str],
+    animals: WordList<'a>,
+    adjectives: WordList<'a>,
+    adjective_count: usize,
+    sampling: WordListVersion,
 }
 
 impl<'a> GoofyAnimals<'a> {
     /// Creates a new `GoofyAnimals` instance with the given animal and adjective lists.
     ///
+    /// Names generated by this instance have two adjectives. Use
+    /// [`GoofyAnimals::new_with_adjective_count`] for a different shape.
+    ///
     /// This constructor performs several checks at compile time to ensure the
     /// provided lists are valid:
     /// - Verifies that the animals list is not empty
     /// - Ensures there are at least two adjectives
-    /// - Checks that there are no trailing newlines in either list
+    /// - Checks that there are no trailing newlines or other empty words in either list
+    /// - Checks that no word appears twice in the same list
+    /// - Checks that words are lowercase and free of carriage returns, leading or
+    ///   trailing whitespace, `-` and `_` separators and non-printable characters
+    ///
+    /// See [`GoofyAnimals::try_new`] for the complete rules and a non-panicking
+    /// alternative.
     ///
     /// # Arguments
     ///
@@ -48,31 +162,170 @@ impl<'a> GoofyAnimals<'a> {
     ///
     /// # Panics
     ///
-    /// This function will panic at compile time if:
-    /// - The animals list is empty
-    /// - The adjectives list has fewer than 2 entries
-    /// - Either list has trailing newlines
+    /// This function will panic at compile time if any of the checks fail, with a
+    /// message naming the problem and the list it was found in, such as
+    /// `duplicate word in adjectives` or `carriage return in animals`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use goofy_animals::GoofyAnimals;
+    ///
+    /// const ANIMALS: GoofyAnimals<'static> = GoofyAnimals::new(&["moth", "owl"], &["big", "red"]);
+    /// ```
+    ///
+    /// Invalid lists fail the build:
+    ///
+    /// ```rust,compile_fail
+    /// use goofy_animals::GoofyAnimals;
+    ///
+    /// // error: evaluation panicked: uppercase letter in animals
+    /// const ANIMALS: GoofyAnimals<'static> = GoofyAnimals::new(&["moth", "Owl"], &["big", "red"]);
+    /// ```
     pub const fn new(animals: &'a [&'a str], adjectives: &'a [&'a str]) -> Self {
-        let total_animals = animals.len();
-        let total_adjectives = adjectives.len();
+        Self::new_with_adjective_count(animals, adjectives, 2)
+    }
 
-        if total_animals < 1 {
-            panic!("empty animals");
+    /// Creates a new `GoofyAnimals` instance generating names with `adjective_count`
+    /// adjectives.
+    ///
+    /// This constructor performs the same checks as [`GoofyAnimals::new`], except that
+    /// the adjectives list must hold at least `adjective_count` entries, as the
+    /// adjectives within a single name are always distinct.
+    ///
+    /// # Arguments
+    ///
+    /// * `animals` - A slice of string slices containing animal names
+    /// * `adjectives` - A slice of string slices containing adjectives
+    /// * `adjective_count` - The number of adjectives per name, up to [`MAX_ADJECTIVES`]
+    ///
+    /// # Returns
+    ///
+    /// A new `GoofyAnimals` instance.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic at compile time if:
+    /// - `adjective_count` is larger than [`MAX_ADJECTIVES`]
+    /// - The adjectives list has fewer than `adjective_count` entries
+    /// - Any of the checks of [`GoofyAnimals::new`] fail
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use goofy_animals::GoofyAnimals;
+    ///
+    /// const SHORT: GoofyAnimals<'static> =
+    ///     GoofyAnimals::new_with_adjective_count(&["moth", "owl"], &["dismal"], 1);
+    /// assert_eq!(SHORT.combinations(), 2);
+    /// ```
+    pub const fn new_with_adjective_count(
+        animals: &'a [&'a str],
+        adjectives: &'a [&'a str],
+        adjective_count: usize,
+    ) -> Self {
+        let animals_list = WordList::from_slice(animals);
+        let adjectives_list = WordList::from_slice(adjectives);
+        match validation::validate_const(animals_list, adjectives_list, adjective_count) {
+            Ok(()) => Self::from_word_lists(animals_list, adjectives_list, adjective_count),
+            Err(error) => panic!("{}", error.panic_message()),
         }
+    }
 
-        if total_adjectives < 2 {
-            panic!("must have at least two adjectives");
-        }
+    /// Creates a new `GoofyAnimals` instance, returning an error for invalid word lists.
+    ///
+    /// Unlike [`GoofyAnimals::new`], this constructor doesn't panic, which makes it
+    /// suitable for word lists that are only known at runtime. It isn't `const`, as
+    /// it looks for duplicate words more cheaply than the `const` checks of
+    /// [`GoofyAnimals::new`] can. Both constructors apply the same rules:
+    /// - The animals list is not empty and there are at least two adjectives
+    /// - No word is empty, including the last one
+    /// - No word appears twice in the same list
+    /// - Words have no carriage returns, no leading or trailing whitespace and no
+    ///   whitespace other than single spaces between their parts, such as in `polar bear`
+    /// - Words contain no `-` or `_` separators
+    /// - Words contain no uppercase letters
+    /// - Words contain no control or other non-printable characters
+    ///
+    /// # Arguments
+    ///
+    /// * `animals` - A slice of string slices containing animal names
+    /// * `adjectives` - A slice of string slices containing adjectives
+    ///
+    /// # Returns
+    ///
+    /// A new `GoofyAnimals` instance, or a [`WordListError`] describing the first
+    /// problem found, including the position of the offending word.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use goofy_animals::{GoofyAnimals, WordListError, WordListKind};
+    ///
+    /// assert!(GoofyAnimals::try_new(&["moth", "polar bear"], &["big", "red"]).is_ok());
+    /// assert_eq!(
+    ///     GoofyAnimals::try_new(&["moth", "polar-bear"], &["big", "red"]).unwrap_err(),
+    ///     WordListError::Separator { list: WordListKind::Animals, index: 1 },
+    /// );
+    /// ```
+    pub fn try_new(
+        animals: &'a [&'a str],
+        adjectives: &'a [&'a str],
+    ) -> Result<Self, WordListError> {
+        Self::try_new_with_adjective_count(animals, adjectives, 2)
+    }
 
-        if const_str::equal!(*animals.last().unwrap(), "") {
-            panic!("trailing newline in animals");
-        }
+    /// Creates a new `GoofyAnimals` instance generating names with `adjective_count`
+    /// adjectives, returning an error for invalid word lists.
+    ///
+    /// See [`GoofyAnimals::try_new`] for the checks performed.
+    ///
+    /// # Arguments
+    ///
+    /// * `animals` - A slice of string slices containing animal names
+    /// * `adjectives` - A slice of string slices containing adjectives
+    /// * `adjective_count` - The number of adjectives per name, up to [`MAX_ADJECTIVES`]
+    ///
+    /// # Returns
+    ///
+    /// A new `GoofyAnimals` instance, or a [`WordListError`] describing the first
+    /// problem found.
+    pub fn try_new_with_adjective_count(
+        animals: &'a [&'a str],
+        adjectives: &'a [&'a str],
+        adjective_count: usize,
+    ) -> Result<Self, WordListError> {
+        Self::try_from_word_lists(
+            WordList::from_slice(animals),
+            WordList::from_slice(adjectives),
+            adjective_count,
+        )
+    }
 
-        if const_str::equal!(*adjectives.last().unwrap(), "") {
-            panic!("trailing newline in adjectives");
-        }
+    /// Creates a new `GoofyAnimals` instance from word lists of any storage, returning
+    /// an error for invalid word lists.
+    pub(crate) fn try_from_word_lists(
+        animals: WordList<'a>,
+        adjectives: WordList<'a>,
+        adjective_count: usize,
+    ) -> Result<Self, WordListError> {
+        validation::validate(animals, adjectives, adjective_count)?;
+        Ok(Self::from_word_lists(animals, adjectives, adjective_count))
+    }
 
-        Self::new_unchecked(animals, adjectives)
+    /// Creates a new `GoofyAnimals` instance from word lists of any storage, without
+    /// performing any validity checks.
+    pub(crate) const fn from_word_lists(
+        animals: WordList<'a>,
+        adjectives: WordList<'a>,
+        adjective_count: usize,
+    ) -> Self {
+        Self {
+            animals,
+            adjectives,
+            adjective_count,
+            sampling: WordListVersion::V1,
+        }
     }
 
     /// Creates a new `GoofyAnimals` instance without performing any validity checks.
@@ -99,46 +352,488 @@ impl<'a> GoofyAnimals<'a> {
     /// Using invalid inputs may result in panics or unexpected behavior when
     /// generating names.
     pub const fn new_unchecked(animals: &'a [&'a str], adjectives: &'a [&'a str]) -> Self {
+        Self::new_unchecked_with_adjective_count(animals, adjectives, 2)
+    }
+
+    /// Creates a new `GoofyAnimals` instance generating names with `adjective_count`
+    /// adjectives, without performing any validity checks.
+    ///
+    /// # Arguments
+    ///
+    /// * `animals` - A slice of string slices containing animal names
+    /// * `adjectives` - A slice of string slices containing adjectives
+    /// * `adjective_count` - The number of adjectives per name
+    ///
+    /// # Returns
+    ///
+    /// A new `GoofyAnimals` instance.
+    ///
+    /// # Safety
+    ///
+    /// This function does not check if:
+    /// - `adjective_count` is at most [`MAX_ADJECTIVES`]
+    /// - The animals list is empty
+    /// - The adjectives list has at least `adjective_count` entries
+    /// - Either list has trailing newlines
+    ///
+    /// Using invalid inputs may result in panics or unexpected behavior when
+    /// generating names.
+    pub const fn new_unchecked_with_adjective_count(
+        animals: &'a [&'a str],
+        adjectives: &'a [&'a str],
+        adjective_count: usize,
+    ) -> Self {
+        Self::from_word_lists(
+            WordList::from_slice(animals),
+            WordList::from_slice(adjectives),
+            adjective_count,
+        )
+    }
+
+    /// Returns a copy of this instance generating names with `adjective_count` adjectives.
+    ///
+    /// The adjective count is checked against the word lists, which have already been
+    /// validated when this instance was created.
+    ///
+    /// # Arguments
+    ///
+    /// * `adjective_count` - The number of adjectives per name, up to [`MAX_ADJECTIVES`]
+    ///
+    /// # Returns
+    ///
+    /// A new `GoofyAnimals` instance sharing the word lists of this one.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `adjective_count` is larger than [`MAX_ADJECTIVES`]
+    /// or than the number of adjectives.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rand::SeedableRng;
+    /// use rand_chacha::ChaCha20Rng;
+    /// use goofy_animals::{DEFAULT_GOOFY_ANIMALS, GoofyAnimals};
+    ///
+    /// const SHORT: GoofyAnimals<'static> = DEFAULT_GOOFY_ANIMALS.with_adjective_count(1);
+    ///
+    /// let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
+    /// assert_eq!(SHORT.generate_name(&mut rng), "dismal-muskox");
+    /// ```
+    pub const fn with_adjective_count(self, adjective_count: usize) -> Self {
+        if adjective_count > MAX_ADJECTIVES {
+            panic!("too many adjectives per name");
+        }
+
+        if self.adjectives.len() < adjective_count {
+            panic!("fewer adjectives than adjectives per name");
+        }
+
         Self {
-            animals,
-            adjectives,
+            adjective_count,
+            ..self
+        }
+    }
+
+    /// Returns a copy of this instance picking words the way `version` does.
+    ///
+    /// The sampling method decides which names a seeded generator produces. Instances
+    /// start out with [`WordListVersion::V1`], which reproduces the names of earlier
+    /// releases; [`WordListVersion::goofy_animals`] sets the method of its version.
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The version whose sampling method to use
+    ///
+    /// # Returns
+    ///
+    /// A new `GoofyAnimals` instance sharing the word lists of this one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rand::SeedableRng;
+    /// use rand_chacha::ChaCha20Rng;
+    /// use goofy_animals::{DEFAULT_GOOFY_ANIMALS, GoofyAnimals, WordListVersion};
+    ///
+    /// const ANIMALS: GoofyAnimals<'static> =
+    ///     DEFAULT_GOOFY_ANIMALS.with_sampling(WordListVersion::V2);
+    ///
+    /// let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
+    /// assert_eq!(ANIMALS.generate_name(&mut rng), "outlying-haunting-firefly");
+    /// ```
+    pub const fn with_sampling(self, version: WordListVersion) -> Self {
+        Self {
+            sampling: version,
+            ..self
         }
     }
 
-    /// Returns a reference to the list of animal names.
+    /// Returns the list of animal names.
     ///
-    /// This can be useful for inspecting or using the animal names directly.
+    /// This can be useful for inspecting or using the animal names directly. It's the
+    /// same as [`GoofyAnimals::animal_list`].
     ///
     /// # Returns
     ///
-    /// A slice of string slices containing the animal names.
-    pub fn get_animals(&self) -> &'a [&'a str] {
+    /// A [`WordList`] containing the animal names.
+    pub const fn get_animals(&self) -> WordList<'a> {
         self.animals
     }
 
-    /// Returns a reference to the list of adjectives.
+    /// Returns the list of adjectives.
     ///
-    /// This can be useful for inspecting or using the adjectives directly.
+    /// This can be useful for inspecting or using the adjectives directly. It's the
+    /// same as [`GoofyAnimals::adjective_list`].
     ///
     /// # Returns
     ///
-    /// A slice of string slices containing the adjectives.
-    pub fn get_adjectives(&self) -> &'a [&'a str] {
+    /// A [`WordList`] containing the adjectives.
+    pub const fn get_adjectives(&self) -> WordList<'a> {
         self.adjectives
     }
 
-    /// Generates the individual parts of a goofy name: two adjectives and an animal.
+    /// Returns the list of animal names, whatever its storage.
+    ///
+    /// # Returns
+    ///
+    /// A [`WordList`] containing the animal names.
+    pub const fn animal_list(&self) -> WordList<'a> {
+        self.animals
+    }
+
+    /// Returns the list of adjectives, whatever its storage.
+    ///
+    /// # Returns
+    ///
+    /// A [`WordList`] containing the adjectives.
+    pub const fn adjective_list(&self) -> WordList<'a> {
+        self.adjectives
+    }
+
+    /// Returns the animals or the adjectives.
+    pub(crate) const fn word_list(&self, list: WordListKind) -> WordList<'a> {
+        match list {
+            WordListKind::Animals => self.animals,
+            WordListKind::Adjectives => self.adjectives,
+        }
+    }
+
+    /// Returns the number of adjectives in each generated name.
+    pub const fn adjective_count(&self) -> usize {
+        self.adjective_count
+    }
+
+    /// Returns the version whose sampling method picks the words of each name.
+    pub const fn sampling(&self) -> WordListVersion {
+        self.sampling
+    }
+
+    /// Returns the number of distinct names this instance can generate.
+    ///
+    /// Every name uses `k` different adjectives and one animal, so the name space
+    /// holds `adjectives × (adjectives - 1) × … × (adjectives - k + 1) × animals`
+    /// entries.
+    ///
+    /// # Returns
+    ///
+    /// The size of the name space, saturated at `u64::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use goofy_animals::DEFAULT_GOOFY_ANIMALS;
+    ///
+    /// assert_eq!(DEFAULT_GOOFY_ANIMALS.combinations(), 1300 * 1299 * 355);
+    /// assert_eq!(DEFAULT_GOOFY_ANIMALS.with_adjective_count(0).combinations(), 355);
+    /// ```
+    pub const fn combinations(&self) -> u64 {
+        let adjectives = self.adjectives.len() as u64;
+        let mut total = self.animals.len() as u64;
+
+        let mut slot = 0;
+        while slot < self.adjective_count {
+            total = total.saturating_mul(adjectives.saturating_sub(slot as u64));
+            slot += 1;
+        }
+
+        total
+    }
+
+    /// Returns the length in bytes of the longest name this instance can generate.
+    ///
+    /// The length is computed for kebab-case names, as produced by
+    /// [`GoofyAnimals::write_name`]. It's also an upper bound for the other
+    /// [`NameStyle`]s as long as the word lists only hold ASCII characters and the
+    /// separator of [`NameStyle::Custom`] is a single byte.
+    ///
+    /// Being a `const fn`, it can be used to size a [`GoofyNameBuf`] at compile time.
+    ///
+    /// # Returns
+    ///
+    /// The length of the longest name, in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use goofy_animals::{DEFAULT_GOOFY_ANIMALS, GoofyNameBuf};
+    ///
+    /// const MAX_LEN: usize = DEFAULT_GOOFY_ANIMALS.max_name_len();
+    /// type NameBuf = GoofyNameBuf<MAX_LEN>;
+    ///
+    /// // "inconsequential-quintessential-tyrannosaurus"
+    /// assert_eq!(MAX_LEN, 15 + 14 + 13 + 2);
+    /// ```
+    pub const fn max_name_len(&self) -> usize {
+        // The lengths of the longest adjectives, longest first
+        let mut longest = [0; MAX_ADJECTIVES];
+
+        let mut index = 0;
+        while index < self.adjectives.len() {
+            let mut len = self.adjectives.word(index).len();
+
+            let mut slot = 0;
+            while slot < self.adjective_count {
+                if len > longest[slot] {
+                    let shorter = longest[slot];
+                    longest[slot] = len;
+                    len = shorter;
+                }
+
+                slot += 1;
+            }
+
+            index += 1;
+        }
+
+        let mut animal = 0;
+        let mut index = 0;
+        while index < self.animals.len() {
+            if self.animals.word(index).len() > animal {
+                animal = self.animals.word(index).len();
+            }
+
+            index += 1;
+        }
+
+        let mut total = animal + self.adjective_count;
+        let mut slot = 0;
+        while slot < self.adjective_count {
+            total += longest[slot];
+            slot += 1;
+        }
+
+        total
+    }
+
+    /// Returns the name at the given position in the name space.
+    ///
+    /// Names are ordered by the position of the first adjective, then the following
+    /// adjectives and finally the animal in their word lists. Together with
+    /// [`GoofyAnimals::index_of`] this forms a bijection between the names and the
+    /// integers in `0..combinations()`, which makes it possible to store a name as
+    /// a single integer.
     ///
-    /// This function selects two different adjectives and one animal randomly using the
-    /// provided random number generator. It ensures the two adjectives are not the same.
+    /// The bijection only holds as long as no two names are written alike, which can
+    /// happen with multi-word adjectives: with `a`, `a b`, `b c` and `c`, both
+    /// `a` + `b c` and `a b` + `c` are written `a-b-c`. `index_of` then returns the
+    /// smaller of their positions.
     ///
     /// # Arguments
     ///
-    /// * `rng` - A mutable reference to any random number generator that implements the `Rng` trait.
+    /// * `index` - The position of the name, in `0..combinations()`
     ///
     /// # Returns
     ///
-    /// A tuple containing three string slices: `(adjective1, adjective2, animal)`.
+    /// The name at `index`, or `None` if the index is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use goofy_animals::DEFAULT_GOOFY_ANIMALS;
+    ///
+    /// let name = DEFAULT_GOOFY_ANIMALS.nth_name(0).unwrap();
+    /// assert_eq!(name.adjectives(), ["abandoned", "able"]);
+    /// assert_eq!(name.animal(), "aardvark");
+    ///
+    /// assert!(DEFAULT_GOOFY_ANIMALS.nth_name(DEFAULT_GOOFY_ANIMALS.combinations()).is_none());
+    /// ```
+    pub fn nth_name(&self, index: u64) -> Option<GoofyName<'a>> {
+        if index >= self.combinations() {
+            return None;
+        }
+
+        let animals = self.animals.len() as u64;
+        let animal = (index % animals) as usize;
+        let mut index = index / animals;
+
+        // Each adjective is drawn from the list with the previous ones removed
+        let mut ranks = [0; MAX_ADJECTIVES];
+        for slot in (0..self.adjective_count).rev() {
+            let remaining = (self.adjectives.len() - slot) as u64;
+            ranks[slot] = (index % remaining) as usize;
+            index /= remaining;
+        }
+
+        let mut adjectives = [0; MAX_ADJECTIVES];
+        for slot in 0..self.adjective_count {
+            adjectives[slot] = nth_unused(ranks[slot], &adjectives[..slot]);
+        }
+
+        Some(GoofyName::from_indices(
+            self,
+            &adjectives[..self.adjective_count],
+            animal,
+        ))
+    }
+
+    /// Returns the position of a name in the name space.
+    ///
+    /// This is the inverse of [`GoofyAnimals::nth_name`]. Multi-word entries are
+    /// matched in every way they can split the name, so that `big-red-moth` is found
+    /// with both `big` and `big red` in the adjectives list.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - A name in the `adjective-adjective-animal` form
+    ///
+    /// # Returns
+    ///
+    /// The position of the name, or `None` if it's not a name this instance could
+    /// have generated. If the name can be read in several ways, the smallest position
+    /// is returned. It's also `None` for names past `u64::MAX`, which only exist when
+    /// [`GoofyAnimals::combinations`] saturates.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use goofy_animals::DEFAULT_GOOFY_ANIMALS;
+    ///
+    /// let index = DEFAULT_GOOFY_ANIMALS.index_of("outlying-haunting-firefly").unwrap();
+    /// let name = DEFAULT_GOOFY_ANIMALS.nth_name(index).unwrap();
+    /// assert_eq!(name.adjectives(), ["outlying", "haunting"]);
+    /// assert_eq!(name.animal(), "firefly");
+    ///
+    /// assert_eq!(DEFAULT_GOOFY_ANIMALS.index_of("dismal-dismal-moth"), None);
+    /// ```
+    pub fn index_of(&self, name: &str) -> Option<u64> {
+        let mut first = None;
+        self.each_reading(
+            name.split('-'),
+            &|list, parts, found| {
+                let Some(first) = parts.clone().next() else {
+                    return;
+                };
+
+                for (index, entry) in self.word_list(list).iter().enumerate() {
+                    if !entry.starts_with(first) {
+                        continue;
+                    }
+
+                    if let Some(rest) = match_kebab(entry, parts.clone()) {
+                        found(index, rest);
+                    }
+                }
+            },
+            &mut |adjectives, animal, mut rest| {
+                if rest.next().is_some() {
+                    return;
+                }
+
+                let key = order_key(adjectives, animal);
+                if first.is_none_or(|first| key < first) {
+                    first = Some(key);
+                }
+            },
+        );
+
+        let (adjectives, animal) = first?;
+        self.rank(&adjectives[..self.adjective_count], animal)
+    }
+
+    /// Calls `found` with every way to read `words` as distinct adjectives followed
+    /// by an animal: the positions of the adjectives and the animal, and the words
+    /// left after the animal.
+    ///
+    /// `matches` is called with the word list to match and the next words, and calls
+    /// its last argument with the position of every entry matching them and the words
+    /// following the entry.
+    pub(crate) fn each_reading<W>(
+        &self,
+        words: W,
+        matches: &impl Fn(WordListKind, W, &mut dyn FnMut(usize, W)),
+        found: &mut dyn FnMut(&[usize], usize, W),
+    ) {
+        self.read_slot(0, words, &mut [0; MAX_ADJECTIVES], matches, found);
+    }
+
+    /// Reads the word in `slot` and the ones after it, see
+    /// [`GoofyAnimals::each_reading`]. `chosen` holds the adjectives read so far.
+    fn read_slot<W>(
+        &self,
+        slot: usize,
+        words: W,
+        chosen: &mut [usize; MAX_ADJECTIVES],
+        matches: &impl Fn(WordListKind, W, &mut dyn FnMut(usize, W)),
+        found: &mut dyn FnMut(&[usize], usize, W),
+    ) {
+        if slot == self.adjective_count {
+            matches(WordListKind::Animals, words, &mut |animal, rest| {
+                found(&chosen[..slot], animal, rest);
+            });
+            return;
+        }
+
+        matches(WordListKind::Adjectives, words, &mut |adjective, rest| {
+            if !chosen[..slot].contains(&adjective) {
+                chosen[slot] = adjective;
+                self.read_slot(slot + 1, rest, chosen, matches, found);
+            }
+        });
+    }
+
+    /// Computes the position in the name space of distinct adjective indices and an
+    /// animal index, or `None` if it doesn't fit in a `u64`.
+    fn rank(&self, adjectives: &[usize], animal: usize) -> Option<u64> {
+        let mut index: u64 = 0;
+        for (slot, &adjective) in adjectives.iter().enumerate() {
+            let remaining = (self.adjectives.len() - slot) as u64;
+            index = index
+                .checked_mul(remaining)?
+                .checked_add(unused_rank(adjective, &adjectives[..slot]) as u64)?;
+        }
+
+        index
+            .checked_mul(self.animals.len() as u64)?
+            .checked_add(animal as u64)
+    }
+
+    /// Generates the individual parts of a goofy name: adjectives and an animal.
+    ///
+    /// This function selects [`GoofyAnimals::adjective_count`] different adjectives
+    /// (two by default) and one animal randomly using the provided random number
+    /// generator. It ensures the adjectives are not the same.
+    ///
+    /// How the words are picked depends on [`GoofyAnimals::sampling`]:
+    ///
+    /// * [`WordListVersion::V1`] draws every adjective from the whole list and starts
+    ///   over while any two of them are the same, then draws the animal. This
+    ///   reproduces the names of earlier releases for a given seed, but the number of
+    ///   words taken from `rng` is unbounded.
+    /// * [`WordListVersion::V2`] makes one [`sample_below`] draw per word, the
+    ///   adjectives first and the animal last, so it takes exactly
+    ///   `adjective_count + 1` words from `rng`. Each adjective is drawn from the
+    ///   adjectives left after removing the previous ones, so with two adjectives each
+    ///   ordered pair of distinct adjectives is chosen with probability
+    ///   `1 / n × 1 / (n - 1)`, up to the bias of [`sample_below`].
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - A mutable reference to any random number generator that implements [`RandomSource`].
+    ///
+    /// # Returns
+    ///
+    /// A [`GoofyName`] holding the chosen words and their positions in the word lists.
     ///
     /// # Examples
     ///
@@ -149,34 +844,81 @@ impl<'a> GoofyAnimals<'a> {
     ///
     /// // Use a seeded RNG for deterministic output
     /// let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
-    /// let (adj1, adj2, animal) = DEFAULT_GOOFY_ANIMALS.generate_name_parts(&mut rng);
-    /// assert_eq!(adj1, "dismal");
-    /// assert_eq!(adj2, "outlying");
-    /// assert_eq!(animal, "moth");
+    /// let name = DEFAULT_GOOFY_ANIMALS.generate_name_parts(&mut rng);
+    /// assert_eq!(name.adjectives(), ["dismal", "outlying"]);
+    /// assert_eq!(name.animal(), "moth");
     /// ```
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(rng), level = tracing::Level::TRACE))]
-    pub fn generate_name_parts(&self, rng: &mut impl Rng) -> (&'a str, &'a str, &'a str) {
-        let (adjective_one, adjective_two) = loop {
-            let one = rng.random_range(0..self.adjectives.len());
-            let two = rng.random_range(0..self.adjectives.len());
+    pub fn generate_name_parts(&self, rng: &mut (impl RandomSource + ?Sized)) -> GoofyName<'a> {
+        let mut adjectives = [0; MAX_ADJECTIVES];
+        let adjectives = &mut adjectives[..self.adjective_count];
+        let animal = match self.sampling {
+            WordListVersion::V1 => {
+                loop {
+                    for adjective in adjectives.iter_mut() {
+                        *adjective = sample_index_v1(rng, self.adjectives.len());
+                    }
+
+                    if !has_repeats(adjectives) {
+                        break;
+                    }
+                }
 
-            if one == two {
-                continue;
+                sample_index_v1(rng, self.animals.len())
             }
+            WordListVersion::V2 => {
+                for slot in 0..adjectives.len() {
+                    let rank = sample_index(rng, self.adjectives.len() - slot);
 
-            break (one, two);
-        };
+                    // Skip over the adjectives already chosen to keep them distinct
+                    adjectives[slot] = nth_unused(rank, &adjectives[..slot]);
+                }
 
-        let animal = rng.random_range(0..self.animals.len());
+                sample_index(rng, self.animals.len())
+            }
+        };
+        let adjectives = &*adjectives;
 
         #[cfg(feature = "tracing")]
-        tracing::trace!(adjective_one, adjective_two, animal, "generated name");
+        tracing::trace!(?adjectives, animal, "generated name");
 
-        (
-            self.adjectives[adjective_one],
-            self.adjectives[adjective_two],
-            self.animals[animal],
-        )
+        GoofyName::from_indices(self, adjectives, animal)
+    }
+
+    /// Generates a goofy name and writes it to `out` in the format `adjective-adjective-animal`.
+    ///
+    /// Unlike `GoofyAnimals::generate_name`, this doesn't need the `alloc` feature.
+    /// Together with [`GoofyNameBuf`] and [`GoofyAnimals::max_name_len`] names can be
+    /// rendered entirely on the stack.
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - The sink receiving the name
+    /// * `rng` - A mutable reference to any random number generator that implements [`RandomSource`].
+    ///
+    /// # Returns
+    ///
+    /// The result of writing to `out`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rand::SeedableRng;
+    /// use rand_chacha::ChaCha20Rng;
+    /// use goofy_animals::{DEFAULT_GOOFY_ANIMALS, GoofyNameBuf};
+    ///
+    /// let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
+    /// let mut name = GoofyNameBuf::<{ DEFAULT_GOOFY_ANIMALS.max_name_len() }>::new();
+    /// DEFAULT_GOOFY_ANIMALS.write_name(&mut name, &mut rng).unwrap();
+    /// assert_eq!(name, "dismal-outlying-moth");
+    /// ```
+    #[inline]
+    pub fn write_name(
+        &self,
+        out: &mut impl core::fmt::Write,
+        rng: &mut impl RandomSource,
+    ) -> core::fmt::Result {
+        write!(out, "{}", self.generate_name_parts(rng))
     }
 
     /// Generates a complete goofy name as a string in the format `adjective-adjective-animal`.
@@ -186,7 +928,7 @@ impl<'a> GoofyAnimals<'a> {
     ///
     /// # Arguments
     ///
-    /// * `rng` - A mutable reference to any random number generator that implements the `Rng` trait.
+    /// * `rng` - A mutable reference to any random number generator that implements [`RandomSource`].
     ///
     /// # Returns
     ///
@@ -211,10 +953,53 @@ impl<'a> GoofyAnimals<'a> {
     #[inline]
     #[cfg(feature = "alloc")]
     #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-    pub fn generate_name(&self, rng: &mut impl Rng) -> ::alloc::string::String {
-        let (adjective_one, adjective_two, animal) = self.generate_name_parts(rng);
+    pub fn generate_name(&self, rng: &mut impl RandomSource) -> ::alloc::string::String {
+        use ::alloc::string::ToString;
+
+        self.generate_name_parts(rng).to_string()
+    }
+
+    /// Generates a complete goofy name as a string in the given style.
+    ///
+    /// Multi-word entries such as `polar bear` are split into their words, so they
+    /// follow the style like every other word of the name.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - A mutable reference to any random number generator that implements [`RandomSource`].
+    /// * `style` - The casing and separator of the name
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the generated name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rand::SeedableRng;
+    /// use rand_chacha::ChaCha20Rng;
+    /// use goofy_animals::{DEFAULT_GOOFY_ANIMALS, NameStyle};
+    ///
+    /// // Use a seeded RNG for deterministic output
+    /// let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
+    /// let name = DEFAULT_GOOFY_ANIMALS.generate_name_styled(&mut rng, NameStyle::ScreamingSnake);
+    /// assert_eq!(name, "DISMAL_OUTLYING_MOTH");
+    /// ```
+    ///
+    /// # Feature Flag
+    ///
+    /// This function is only available when the `alloc` feature is enabled.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn generate_name_styled(
+        &self,
+        rng: &mut impl RandomSource,
+        style: NameStyle,
+    ) -> ::alloc::string::String {
+        use ::alloc::string::ToString;
 
-        ::alloc::format!("{adjective_one}-{adjective_two}-{animal}")
+        self.generate_name_parts(rng).styled(style).to_string()
     }
 }
 
@@ -223,10 +1008,66 @@ impl Debug for GoofyAnimals<'_> {
         f.debug_struct("GoofyAnimals")
             .field("total_adjectives", &self.adjectives.len())
             .field("total_animals", &self.animals.len())
+            .field("adjective_count", &self.adjective_count)
+            .field("sampling", &self.sampling)
             .finish()
     }
 }
 
+/// Matches the words of a word list entry against the next kebab-case parts of a name.
+///
+/// Returns the parts following the entry if all of its words matched.
+fn match_kebab<'n>(
+    entry: &str,
+    mut parts: core::str::Split<'n, char>,
+) -> Option<core::str::Split<'n, char>> {
+    for word in entry.split_whitespace() {
+        if parts.next()? != word {
+            return None;
+        }
+    }
+
+    Some(parts)
+}
+
+/// Returns a key ordering names like their positions in the name space, which
+/// unlike the positions themselves never overflows.
+pub(crate) fn order_key(adjectives: &[usize], animal: usize) -> ([usize; MAX_ADJECTIVES], usize) {
+    let mut indices = [0; MAX_ADJECTIVES];
+    indices[..adjectives.len()].copy_from_slice(adjectives);
+    (indices, animal)
+}
+
+/// Returns the `rank`-th index, counting from zero, that is not in the distinct
+/// indices of `chosen`.
+pub(crate) fn nth_unused(rank: usize, chosen: &[usize]) -> usize {
+    // Every chosen index at or below the candidate pushes it one further along
+    let mut index = rank;
+    loop {
+        let next = rank + chosen.iter().filter(|&&used| used <= index).count();
+        if next == index {
+            return index;
+        }
+
+        index = next;
+    }
+}
+
+/// Returns the rank of `index` among the indices that are not in `chosen`.
+///
+/// This is the inverse of [`nth_unused`].
+fn unused_rank(index: usize, chosen: &[usize]) -> usize {
+    index - chosen.iter().filter(|&&used| used < index).count()
+}
+
+/// Returns whether any index appears more than once in `indices`.
+fn has_repeats(indices: &[usize]) -> bool {
+    indices
+        .iter()
+        .enumerate()
+        .any(|(slot, index)| indices[..slot].contains(index))
+}
+
 /// Generates the individual parts of a goofy name using the default word lists.
 ///
 /// This is a convenience function that calls `generate_name_parts` on the
@@ -234,11 +1075,11 @@ impl Debug for GoofyAnimals<'_> {
 ///
 /// # Arguments
 ///
-/// * `rng` - A mutable reference to any random number generator that implements the `Rng` trait.
+/// * `rng` - A mutable reference to any random number generator that implements [`RandomSource`].
 ///
 /// # Returns
 ///
-/// A tuple containing three string slices: `(adjective1, adjective2, animal)`.
+/// A [`GoofyName`] holding the chosen words and their positions in the word lists.
 ///
 /// # Examples
 ///
@@ -249,18 +1090,40 @@ impl Debug for GoofyAnimals<'_> {
 ///
 /// // Use a seeded RNG for deterministic output
 /// let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
-/// let (adj1, adj2, animal) = generate_name_parts(&mut rng);
-/// assert_eq!(adj1, "dismal");
-/// assert_eq!(adj2, "outlying");
-/// assert_eq!(animal, "moth");
+/// let name = generate_name_parts(&mut rng);
+/// assert_eq!(name.adjectives(), ["dismal", "outlying"]);
+/// assert_eq!(name.animal(), "moth");
 /// ```
 ///
 /// See [`GoofyAnimals::generate_name_parts`] for more details.
 #[inline]
-pub fn generate_name_parts(rng: &mut impl Rng) -> (&'static str, &'static str, &'static str) {
+pub fn generate_name_parts(rng: &mut impl RandomSource) -> GoofyName<'static> {
     DEFAULT_GOOFY_ANIMALS.generate_name_parts(rng)
 }
 
+/// Generates a goofy name using the default word lists and writes it to `out`.
+///
+/// This is a convenience function that calls `write_name` on the
+/// `DEFAULT_GOOFY_ANIMALS` instance.
+///
+/// # Arguments
+///
+/// * `out` - The sink receiving the name
+/// * `rng` - A mutable reference to any random number generator that implements [`RandomSource`].
+///
+/// # Returns
+///
+/// The result of writing to `out`.
+///
+/// See [`GoofyAnimals::write_name`] for more details.
+#[inline]
+pub fn write_name(
+    out: &mut impl core::fmt::Write,
+    rng: &mut impl RandomSource,
+) -> core::fmt::Result {
+    DEFAULT_GOOFY_ANIMALS.write_name(out, rng)
+}
+
 /// Generates a complete goofy name as a string using the default word lists.
 ///
 /// This is a convenience function that calls `generate_name` on the
@@ -268,7 +1131,7 @@ pub fn generate_name_parts(rng: &mut impl Rng) -> (&'static str, &'static str, &
 ///
 /// # Arguments
 ///
-/// * `rng` - A mutable reference to any random number generator that implements the `Rng` trait.
+/// * `rng` - A mutable reference to any random number generator that implements [`RandomSource`].
 ///
 /// # Returns
 ///
@@ -295,16 +1158,20 @@ pub fn generate_name_parts(rng: &mut impl Rng) -> (&'static str, &'static str, &
 #[inline]
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-pub fn generate_name(rng: &mut impl Rng) -> ::alloc::string::String {
+pub fn generate_name(rng: &mut impl RandomSource) -> ::alloc::string::String {
     DEFAULT_GOOFY_ANIMALS.generate_name(rng)
 }
 
 #[cfg(test)]
 mod test {
-    use super::DEFAULT_GOOFY_ANIMALS;
+    use super::{DEFAULT_GOOFY_ANIMALS, GoofyAnimals, GoofyName, MAX_ADJECTIVES, WordList};
 
     use pretty_assertions::assert_eq;
 
+    fn parts(name: GoofyName<'_>) -> (::alloc::vec::Vec<&str>, &str) {
+        (name.adjectives().to_vec(), name.animal())
+    }
+
     #[test]
     fn animals() {
         assert_eq!(DEFAULT_GOOFY_ANIMALS.get_animals().len(), 355);
@@ -315,45 +1182,107 @@ mod test {
         assert_eq!(DEFAULT_GOOFY_ANIMALS.get_adjectives().len(), 1300);
     }
 
+    #[test]
+    fn list_getters() {
+        let animals = GoofyAnimals::new(&["moth", "polar bear"], &["big", "red"]);
+        assert_eq!(
+            animals.get_animals(),
+            WordList::from_slice(&["moth", "polar bear"])
+        );
+        assert_eq!(
+            animals.get_adjectives(),
+            WordList::from_slice(&["big", "red"])
+        );
+
+        // Lists that aren't slices of string slices are returned all the same
+        let owned = crate::GoofyAnimalsBuf::from_words(["moth"], ["big", "red"]).unwrap();
+        let owned = owned.as_goofy_animals();
+        assert_eq!(owned.get_animals().get(0), Some("moth"));
+        assert_eq!(owned.get_adjectives(), owned.adjective_list());
+    }
+
+    #[test]
+    #[cfg(feature = "packed-words")]
+    fn packed_getters() {
+        assert_eq!(DEFAULT_GOOFY_ANIMALS.get_animals().as_slice(), None);
+        assert_eq!(
+            DEFAULT_GOOFY_ANIMALS.get_animals().get(246),
+            Some("polar bear")
+        );
+        assert_eq!(DEFAULT_GOOFY_ANIMALS.get_adjectives().iter().count(), 1300);
+    }
+
+    #[test]
+    #[cfg(all(feature = "small-words", feature = "large-words"))]
+    fn word_list_tiers() {
+        use super::{LARGE_GOOFY_ANIMALS, SMALL_GOOFY_ANIMALS};
+
+        assert_eq!(SMALL_GOOFY_ANIMALS.animal_list().len(), 64);
+        assert_eq!(SMALL_GOOFY_ANIMALS.adjective_list().len(), 256);
+        assert_eq!(LARGE_GOOFY_ANIMALS.animal_list().len(), 518);
+        assert_eq!(LARGE_GOOFY_ANIMALS.adjective_list().len(), 1671);
+
+        for (smaller, larger) in [
+            (SMALL_GOOFY_ANIMALS, DEFAULT_GOOFY_ANIMALS),
+            (DEFAULT_GOOFY_ANIMALS, LARGE_GOOFY_ANIMALS),
+        ] {
+            for animal in smaller.animal_list() {
+                assert!(larger.animal_list().iter().any(|word| word == animal));
+            }
+
+            for adjective in smaller.adjective_list() {
+                assert!(larger.adjective_list().iter().any(|word| word == adjective));
+            }
+        }
+
+        assert_eq!(SMALL_GOOFY_ANIMALS.combinations(), 4_177_920);
+        assert_eq!(LARGE_GOOFY_ANIMALS.combinations(), 1_445_515_260);
+
+        let name = SMALL_GOOFY_ANIMALS.nth_name(12_345).unwrap();
+        let text = ::alloc::format!("{name}");
+        assert!(LARGE_GOOFY_ANIMALS.index_of(&text).is_some());
+    }
+
     #[test]
     #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
     fn name_generation() {
         use rand::SeedableRng;
         use rand_chacha::ChaCha20Rng;
 
+        // The names of the first releases, which the default sampling must keep
         let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
 
         assert_eq!(
-            DEFAULT_GOOFY_ANIMALS.generate_name_parts(&mut rng),
-            ("dismal", "outlying", "moth"),
+            parts(DEFAULT_GOOFY_ANIMALS.generate_name_parts(&mut rng)),
+            (::alloc::vec!["dismal", "outlying"], "moth"),
         );
         assert_eq!(
-            DEFAULT_GOOFY_ANIMALS.generate_name_parts(&mut rng),
-            ("healthy", "yellowish", "firefly"),
+            parts(DEFAULT_GOOFY_ANIMALS.generate_name_parts(&mut rng)),
+            (::alloc::vec!["healthy", "yellowish"], "firefly"),
         );
         assert_eq!(
-            DEFAULT_GOOFY_ANIMALS.generate_name_parts(&mut rng),
-            ("flat", "faint", "squirrel"),
+            parts(DEFAULT_GOOFY_ANIMALS.generate_name_parts(&mut rng)),
+            (::alloc::vec!["flat", "faint"], "squirrel"),
         );
         assert_eq!(
-            DEFAULT_GOOFY_ANIMALS.generate_name_parts(&mut rng),
-            ("glorious", "educated", "louse"),
+            parts(DEFAULT_GOOFY_ANIMALS.generate_name_parts(&mut rng)),
+            (::alloc::vec!["glorious", "educated"], "louse"),
         );
         assert_eq!(
-            DEFAULT_GOOFY_ANIMALS.generate_name_parts(&mut rng),
-            ("big", "glittering", "perch"),
+            parts(DEFAULT_GOOFY_ANIMALS.generate_name_parts(&mut rng)),
+            (::alloc::vec!["big", "glittering"], "perch"),
         );
         assert_eq!(
-            DEFAULT_GOOFY_ANIMALS.generate_name_parts(&mut rng),
-            ("relieved", "shadowy", "booby"),
+            parts(DEFAULT_GOOFY_ANIMALS.generate_name_parts(&mut rng)),
+            (::alloc::vec!["relieved", "shadowy"], "booby"),
         );
         assert_eq!(
-            DEFAULT_GOOFY_ANIMALS.generate_name_parts(&mut rng),
-            ("simplistic", "thankful", "panther"),
+            parts(DEFAULT_GOOFY_ANIMALS.generate_name_parts(&mut rng)),
+            (::alloc::vec!["simplistic", "thankful"], "panther"),
         );
         assert_eq!(
-            DEFAULT_GOOFY_ANIMALS.generate_name_parts(&mut rng),
-            ("black", "serene", "marten"),
+            parts(DEFAULT_GOOFY_ANIMALS.generate_name_parts(&mut rng)),
+            (::alloc::vec!["black", "serene"], "marten"),
         );
 
         #[cfg(all(feature = "tracing", feature = "alloc"))]
@@ -373,6 +1302,244 @@ mod test {
         }
     }
 
+    #[test]
+    fn name_ranking() {
+        let total = DEFAULT_GOOFY_ANIMALS.combinations();
+        assert_eq!(total, 599_488_500);
+
+        for index in [0, 1, 354, 355, 461_144, total / 2, total - 1] {
+            let name = DEFAULT_GOOFY_ANIMALS.nth_name(index).unwrap();
+            let text = ::alloc::format!("{name}");
+
+            assert_eq!(DEFAULT_GOOFY_ANIMALS.index_of(&text), Some(index));
+        }
+
+        let last = DEFAULT_GOOFY_ANIMALS.nth_name(total - 1).unwrap();
+        assert_eq!(parts(last), (::alloc::vec!["zigzag", "zesty"], "zebra"));
+
+        let index = DEFAULT_GOOFY_ANIMALS
+            .index_of("dismal-outrageous-polar-bear")
+            .unwrap();
+        let name = DEFAULT_GOOFY_ANIMALS.nth_name(index).unwrap();
+        assert_eq!(name.animal(), "polar bear");
+        assert_eq!(
+            DEFAULT_GOOFY_ANIMALS.index_of("dismal-outrageous-polar bear"),
+            None
+        );
+
+        assert_eq!(DEFAULT_GOOFY_ANIMALS.nth_name(total), None);
+        assert_eq!(DEFAULT_GOOFY_ANIMALS.index_of("dismal-moth"), None);
+        assert_eq!(
+            DEFAULT_GOOFY_ANIMALS.index_of("outlying-haunting-firefly-moth"),
+            None
+        );
+        assert_eq!(
+            DEFAULT_GOOFY_ANIMALS.index_of("dismal-outrageous-unicorn"),
+            None
+        );
+    }
+
+    #[test]
+    fn name_ranking_tiny() {
+        let animals = GoofyAnimals::new(&["cat", "dog"], &["big", "red", "shy"]);
+
+        let names: ::alloc::vec::Vec<_> = (0..animals.combinations())
+            .map(|index| ::alloc::format!("{}", animals.nth_name(index).unwrap()))
+            .collect();
+
+        assert_eq!(
+            names,
+            [
+                "big-red-cat",
+                "big-red-dog",
+                "big-shy-cat",
+                "big-shy-dog",
+                "red-big-cat",
+                "red-big-dog",
+                "red-shy-cat",
+                "red-shy-dog",
+                "shy-big-cat",
+                "shy-big-dog",
+                "shy-red-cat",
+                "shy-red-dog",
+            ]
+        );
+
+        for (index, name) in names.iter().enumerate() {
+            assert_eq!(animals.index_of(name), Some(index as u64));
+        }
+    }
+
+    #[test]
+    fn name_ranking_multi_word() {
+        let animals = crate::fixtures::ANIMALS;
+
+        for index in 0..animals.combinations() {
+            let name = ::alloc::format!("{}", animals.nth_name(index).unwrap());
+            assert_eq!(animals.index_of(&name), Some(index), "{name}");
+        }
+
+        assert_eq!(animals.index_of("big-red-moth"), Some(4));
+        assert_eq!(animals.index_of("big-red-polar-bear"), Some(5));
+        assert_eq!(animals.index_of("big-red-shy-moth"), Some(20));
+        assert_eq!(animals.index_of("shy-shy-moth"), None);
+
+        // Both readings of `a-b-c-x` are valid, the first one wins
+        let ambiguous = GoofyAnimals::new(&["x"], &["a", "a b", "b c", "c"]);
+        let first = ambiguous.index_of("a-b-c-x").unwrap();
+        assert_eq!(
+            parts(ambiguous.nth_name(first).unwrap()),
+            (::alloc::vec!["a", "b c"], "x")
+        );
+        assert!(
+            (0..ambiguous.combinations())
+                .filter(
+                    |&index| ::alloc::format!("{}", ambiguous.nth_name(index).unwrap())
+                        == "a-b-c-x"
+                )
+                .eq([first, 5])
+        );
+    }
+
+    #[test]
+    fn adjective_counts() {
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha20Rng;
+
+        let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
+
+        for count in 0..=MAX_ADJECTIVES {
+            let animals = DEFAULT_GOOFY_ANIMALS.with_adjective_count(count);
+            assert_eq!(animals.adjective_count(), count);
+
+            for _ in 0..1000 {
+                let name = animals.generate_name_parts(&mut rng);
+                let mut adjectives = name.adjectives().to_vec();
+                adjectives.sort();
+                adjectives.dedup();
+
+                assert_eq!(adjectives.len(), count);
+                assert_eq!(
+                    animals.index_of(&::alloc::format!("{name}")),
+                    animals.rank(name.adjective_indices(), name.animal_index()),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn name_ranking_adjective_counts() {
+        const ADJECTIVES: &[&str] = &["big", "red", "shy", "wet"];
+
+        for (count, expected) in [(0, 2), (1, 8), (2, 24), (3, 48), (4, 48)] {
+            let animals =
+                GoofyAnimals::new_with_adjective_count(&["cat", "dog"], ADJECTIVES, count);
+            assert_eq!(animals.combinations(), expected);
+
+            let mut names: ::alloc::vec::Vec<_> = (0..expected)
+                .map(|index| ::alloc::format!("{}", animals.nth_name(index).unwrap()))
+                .collect();
+
+            for (index, name) in names.iter().enumerate() {
+                assert_eq!(animals.index_of(name), Some(index as u64));
+            }
+
+            names.sort();
+            names.dedup();
+            assert_eq!(names.len() as u64, expected);
+        }
+
+        let single = GoofyAnimals::new_with_adjective_count(&["cat"], &["big"], 1);
+        assert_eq!(
+            parts(single.nth_name(0).unwrap()),
+            (::alloc::vec!["big"], "cat")
+        );
+        assert_eq!(single.index_of("big-cat"), Some(0));
+        assert_eq!(single.index_of("big-big-cat"), None);
+    }
+
+    #[test]
+    fn name_ranking_overflow() {
+        let owned: ::alloc::vec::Vec<_> = (0..70_000).map(|i| ::alloc::format!("a{i}")).collect();
+        let adjectives: ::alloc::vec::Vec<&str> = owned.iter().map(|w| w.as_str()).collect();
+        let animals = GoofyAnimals::new_unchecked_with_adjective_count(&["moth"], &adjectives, 4);
+
+        // 70000 × 69999 × 69998 × 69997 names don't fit in a u64
+        assert_eq!(animals.combinations(), u64::MAX);
+        assert_eq!(animals.index_of("a69999-a69998-a69997-a69996-moth"), None);
+
+        let last = animals.nth_name(u64::MAX - 1).unwrap();
+        assert_eq!(
+            animals.index_of(&::alloc::format!("{last}")),
+            Some(u64::MAX - 1)
+        );
+    }
+
+    #[test]
+    fn max_name_len() {
+        const ADJECTIVES: &[&str] = &["a", "bbb", "cc", "dddd", "e"];
+
+        for count in 0..=MAX_ADJECTIVES {
+            let animals =
+                GoofyAnimals::new_with_adjective_count(&["ox", "polar bear"], ADJECTIVES, count);
+
+            let longest = (0..animals.combinations())
+                .map(|index| ::alloc::format!("{}", animals.nth_name(index).unwrap()).len())
+                .max();
+
+            assert_eq!(Some(animals.max_name_len()), longest);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate word in adjectives")]
+    fn duplicate_adjectives() {
+        GoofyAnimals::new(&["cat"], &["big", "red", "big"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "carriage return in animals")]
+    fn carriage_return() {
+        GoofyAnimals::new(&["cat\r", "dog"], &["big", "red"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "uppercase letter in animals")]
+    fn uppercase() {
+        GoofyAnimals::new(&["Cat"], &["big", "red"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "too many adjectives per name")]
+    fn too_many_adjectives() {
+        DEFAULT_GOOFY_ANIMALS.with_adjective_count(MAX_ADJECTIVES + 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "fewer adjectives than adjectives per name")]
+    fn too_few_adjectives() {
+        GoofyAnimals::new_with_adjective_count(&["cat"], &["big", "red"], 3);
+    }
+
+    #[test]
+    fn v2_draw_count() {
+        use crate::{RandomFn, WordListVersion};
+
+        for adjective_count in 0..=MAX_ADJECTIVES {
+            let animals = DEFAULT_GOOFY_ANIMALS
+                .with_sampling(WordListVersion::V2)
+                .with_adjective_count(adjective_count);
+
+            // Even a generator repeating itself never makes V2 draw again
+            let mut draws = 0;
+            animals.generate_name_parts(&mut RandomFn(|| {
+                draws += 1;
+                0
+            }));
+            assert_eq!(draws, adjective_count + 1);
+        }
+    }
+
     #[test]
     #[cfg(feature = "alloc")]
     fn name_generation_alloc() {
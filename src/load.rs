@@ -0,0 +1,296 @@
+use core::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use crate::{GoofyAnimalsBuf, WordListError, WordListKind};
+
+/// The reason word lists couldn't be loaded by
+/// [`GoofyAnimalsBuf::from_readers`] or [`GoofyAnimalsBuf::from_paths`].
+///
+/// # Feature Flag
+///
+/// This type is only available when the `std` feature is enabled.
+#[derive(Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub enum LoadError {
+    /// Reading one of the word lists failed.
+    Io {
+        list: WordListKind,
+        source: std::io::Error,
+    },
+    /// The word lists were read, but rejected by the rules of
+    /// [`GoofyAnimals::try_new`](crate::GoofyAnimals::try_new).
+    ///
+    /// The positions in `error` count words only, while `line` is the line number of
+    /// the offending word, counting from one. It's `None` for errors that aren't
+    /// about a single word. For a duplicate word, `first_line` is the line number of
+    /// its first occurrence, and `None` otherwise.
+    WordList {
+        error: WordListError,
+        line: Option<usize>,
+        first_line: Option<usize>,
+    },
+}
+
+impl GoofyAnimalsBuf {
+    /// Loads word lists from readers, one word per line.
+    ///
+    /// The format is the one of the built-in word lists, except that blank lines and
+    /// lines starting with `#` are skipped, and lines may end with `\r\n`. Names
+    /// generated from this instance have two adjectives, so loading the built-in
+    /// lists gives an instance equivalent to
+    /// [`DEFAULT_GOOFY_ANIMALS`](crate::DEFAULT_GOOFY_ANIMALS).
+    ///
+    /// # Arguments
+    ///
+    /// * `animals` - The reader holding the animal names
+    /// * `adjectives` - The reader holding the adjectives
+    ///
+    /// # Returns
+    ///
+    /// A new `GoofyAnimalsBuf` instance, or a [`LoadError`] if reading failed or the
+    /// word lists are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use goofy_animals::{GoofyAnimalsBuf, LoadError, WordListError, WordListKind};
+    ///
+    /// let animals = "# Mascots\nmoth\n\nowl\n";
+    /// let adjectives = "big\nred\nBig\n";
+    ///
+    /// match GoofyAnimalsBuf::from_readers(animals.as_bytes(), adjectives.as_bytes()) {
+    ///     Err(LoadError::WordList { error, line, .. }) => {
+    ///         assert_eq!(error, WordListError::Uppercase { list: WordListKind::Adjectives, index: 2 });
+    ///         assert_eq!(line, Some(3));
+    ///     }
+    ///     other => panic!("unexpected {other:?}"),
+    /// }
+    /// ```
+    ///
+    /// # Feature Flag
+    ///
+    /// This function is only available when the `std` feature is enabled.
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn from_readers(
+        animals: impl BufRead,
+        adjectives: impl BufRead,
+    ) -> Result<Self, LoadError> {
+        let (animals, animal_lines) = read_words(animals, WordListKind::Animals)?;
+        let (adjectives, adjective_lines) = read_words(adjectives, WordListKind::Adjectives)?;
+
+        Self::try_new(animals, adjectives).map_err(|error| {
+            let lines = match error.list() {
+                Some(WordListKind::Animals) => &animal_lines,
+                Some(WordListKind::Adjectives) => &adjective_lines,
+                None => {
+                    return LoadError::WordList {
+                        error,
+                        line: None,
+                        first_line: None,
+                    };
+                }
+            };
+
+            let first_line = match error {
+                WordListError::DuplicateWord { first, .. } => Some(lines[first]),
+                _ => None,
+            };
+
+            LoadError::WordList {
+                error,
+                line: error.index().map(|index| lines[index]),
+                first_line,
+            }
+        })
+    }
+
+    /// Loads word lists from files, one word per line.
+    ///
+    /// See [`GoofyAnimalsBuf::from_readers`] for the format of the files.
+    ///
+    /// # Arguments
+    ///
+    /// * `animals` - The path of the file holding the animal names
+    /// * `adjectives` - The path of the file holding the adjectives
+    ///
+    /// # Returns
+    ///
+    /// A new `GoofyAnimalsBuf` instance, or a [`LoadError`] if reading failed or the
+    /// word lists are invalid.
+    ///
+    /// # Feature Flag
+    ///
+    /// This function is only available when the `std` feature is enabled.
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn from_paths(
+        animals: impl AsRef<Path>,
+        adjectives: impl AsRef<Path>,
+    ) -> Result<Self, LoadError> {
+        let open = |path: &Path, list| {
+            File::open(path)
+                .map(BufReader::new)
+                .map_err(|source| LoadError::Io { list, source })
+        };
+
+        Self::from_readers(
+            open(animals.as_ref(), WordListKind::Animals)?,
+            open(adjectives.as_ref(), WordListKind::Adjectives)?,
+        )
+    }
+}
+
+/// Reads the words of a list, along with the line number of each word.
+fn read_words(
+    reader: impl BufRead,
+    list: WordListKind,
+) -> Result<(Vec<String>, Vec<usize>), LoadError> {
+    let mut words = Vec::new();
+    let mut lines = Vec::new();
+
+    for (number, line) in reader.lines().enumerate() {
+        let line = line.map_err(|source| LoadError::Io { list, source })?;
+
+        let content = line.trim();
+        if content.is_empty() || content.starts_with('#') {
+            continue;
+        }
+
+        words.push(line);
+        lines.push(number + 1);
+    }
+
+    Ok((words, lines))
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Io { list, source } => write!(f, "failed to read {list}: {source}"),
+            Self::WordList {
+                error: WordListError::DuplicateWord { list, .. },
+                line: Some(line),
+                first_line: Some(first_line),
+            } => write!(
+                f,
+                "duplicate word in {list} at line {line}, first seen at line {first_line}"
+            ),
+            Self::WordList {
+                error,
+                line: Some(line),
+                ..
+            } => write!(f, "{error} (line {line})"),
+            Self::WordList {
+                error, line: None, ..
+            } => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            Self::WordList { error, .. } => Some(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::LoadError;
+    use crate::{DEFAULT_GOOFY_ANIMALS, GoofyAnimalsBuf, WordListError, WordListKind};
+
+    use pretty_assertions::assert_eq;
+
+    fn load(animals: &str, adjectives: &str) -> Result<GoofyAnimalsBuf, LoadError> {
+        GoofyAnimalsBuf::from_readers(animals.as_bytes(), adjectives.as_bytes())
+    }
+
+    #[test]
+    fn from_paths() {
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha20Rng;
+
+        let animals = GoofyAnimalsBuf::from_paths(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/src/data/en_animals.txt"),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/src/data/en_adjectives.txt"),
+        )
+        .unwrap();
+
+        let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
+        let mut default_rng = ChaCha20Rng::seed_from_u64(0x1337);
+        for _ in 0..100 {
+            assert_eq!(
+                animals.as_goofy_animals().generate_name(&mut rng),
+                DEFAULT_GOOFY_ANIMALS.generate_name(&mut default_rng)
+            );
+        }
+
+        assert!(matches!(
+            GoofyAnimalsBuf::from_paths("does/not/exist", "does/not/exist"),
+            Err(LoadError::Io {
+                list: WordListKind::Animals,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn comments_and_blank_lines() {
+        let animals = load("# Mascots\r\nmoth\r\n\r\n  # More\n\t\nowl", "big\nred\n").unwrap();
+
+        assert_eq!(
+            animals,
+            GoofyAnimalsBuf::from_words(["moth", "owl"], ["big", "red"]).unwrap()
+        );
+    }
+
+    #[test]
+    fn line_numbers() {
+        let error = load("moth\n# Mascots\n\nowl\nmoth", "big\nred").unwrap_err();
+        assert!(matches!(
+            error,
+            LoadError::WordList {
+                error: WordListError::DuplicateWord {
+                    list: WordListKind::Animals,
+                    index: 2,
+                    first: 0
+                },
+                line: Some(5),
+                first_line: Some(1)
+            }
+        ));
+        assert_eq!(
+            error.to_string(),
+            "duplicate word in animals at line 5, first seen at line 1"
+        );
+        assert_eq!(
+            load("moth", "# Sizes\n\nbig\nred\nbig")
+                .unwrap_err()
+                .to_string(),
+            "duplicate word in adjectives at line 5, first seen at line 3"
+        );
+
+        assert!(matches!(
+            load("# Nothing yet\n", "big\nred"),
+            Err(LoadError::WordList {
+                error: WordListError::EmptyAnimals,
+                line: None,
+                first_line: None
+            })
+        ));
+        assert!(matches!(
+            load("moth", "big\n\n # One is missing\n polar bear"),
+            Err(LoadError::WordList {
+                error: WordListError::LeadingOrTrailingWhitespace {
+                    list: WordListKind::Adjectives,
+                    index: 1
+                },
+                line: Some(4),
+                first_line: None
+            })
+        ));
+    }
+}
@@ -0,0 +1,234 @@
+/// Builds a validated `const` [`GoofyAnimals`](crate::GoofyAnimals) from word list files.
+///
+/// The files are embedded with [`include_str!`], so their paths are relative to the
+/// file invoking the macro. They hold one word per line, in the format of the
+/// built-in word lists, and may end with a newline.
+///
+/// The word lists are checked in `const` context with the rules of
+/// [`GoofyAnimals::new`](crate::GoofyAnimals::new), so invalid lists fail the build
+/// wherever the macro is used. An optional `adjective_count` sets the number of
+/// adjectives per name, which defaults to two.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use goofy_animals::{GoofyAnimals, goofy_animals};
+///
+/// const MASCOTS: GoofyAnimals<'static> = goofy_animals!(
+///     animals = "words/mascots.txt",
+///     adjectives = "words/adjectives.txt",
+/// );
+///
+/// const SHORT_MASCOTS: GoofyAnimals<'static> = goofy_animals!(
+///     animals = "words/mascots.txt",
+///     adjectives = "words/adjectives.txt",
+///     adjective_count = 1,
+/// );
+/// ```
+#[macro_export]
+macro_rules! goofy_animals {
+    (animals = $animals:expr, adjectives = $adjectives:expr $(,)?) => {
+        $crate::goofy_animals!(
+            animals = $animals,
+            adjectives = $adjectives,
+            adjective_count = 2
+        )
+    };
+    (animals = $animals:expr, adjectives = $adjectives:expr, adjective_count = $adjective_count:expr $(,)?) => {{
+        const GOOFY_ANIMALS: $crate::GoofyAnimals<'static> = $crate::__private::goofy_animals(
+            $crate::__word_list!($animals),
+            $crate::__word_list!($adjectives),
+            $adjective_count,
+        );
+
+        GOOFY_ANIMALS
+    }};
+}
+
+/// Embeds a word list file as a [`WordList`](crate::WordList) of string slices.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __word_list {
+    ($path:expr) => {
+        $crate::WordList::from_slice(&$crate::__private::const_str::split!(
+            $crate::__private::strip_final_newline(::core::include_str!($path)),
+            "\n"
+        ))
+    };
+}
+
+/// Builds one of the built-in `GoofyAnimals` instances like [`goofy_animals!`], packing
+/// its word lists with the `packed-words` feature.
+macro_rules! builtin_goofy_animals {
+    (animals = $animals:expr, adjectives = $adjectives:expr $(,)?) => {{
+        const GOOFY_ANIMALS: $crate::GoofyAnimals<'static> = $crate::__private::goofy_animals(
+            builtin_word_list!($animals),
+            builtin_word_list!($adjectives),
+            2,
+        );
+
+        GOOFY_ANIMALS
+    }};
+}
+
+/// Embeds a built-in word list file as a [`WordList`](crate::WordList) of string slices.
+#[cfg(not(feature = "packed-words"))]
+macro_rules! builtin_word_list {
+    ($path:expr) => {
+        $crate::__word_list!($path)
+    };
+}
+
+/// Embeds a built-in word list file as a packed [`WordList`](crate::WordList): the
+/// text of the file and a table of two-byte word offsets, instead of a string slice
+/// per word.
+#[cfg(feature = "packed-words")]
+macro_rules! builtin_word_list {
+    ($path:expr) => {{
+        const TEXT: &str = $crate::__private::strip_final_newline(::core::include_str!($path));
+        const OFFSETS: [u16; $crate::macros::word_count(TEXT) + 1] =
+            $crate::macros::word_offsets(TEXT);
+
+        $crate::WordList::from_packed(TEXT, &OFFSETS)
+    }};
+}
+
+/// Returns the number of newline-separated words in `text`.
+#[cfg(feature = "packed-words")]
+pub(crate) const fn word_count(text: &str) -> usize {
+    let bytes = text.as_bytes();
+
+    let mut count = 1;
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'\n' {
+            count += 1;
+        }
+
+        index += 1;
+    }
+
+    count
+}
+
+/// Returns the offset of every newline-separated word in `text`, followed by the
+/// length of `text` plus one, as if it ended with a newline.
+#[cfg(feature = "packed-words")]
+pub(crate) const fn word_offsets<const N: usize>(text: &str) -> [u16; N] {
+    if text.len() >= u16::MAX as usize {
+        panic!("word list too long for packed storage");
+    }
+
+    let bytes = text.as_bytes();
+    let mut offsets = [0; N];
+
+    let mut word = 1;
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'\n' {
+            offsets[word] = index as u16 + 1;
+            word += 1;
+        }
+
+        index += 1;
+    }
+
+    offsets[word] = bytes.len() as u16 + 1;
+    offsets
+}
+
+/// Items used by the expansion of [`goofy_animals!`], not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use const_str;
+
+    use crate::{GoofyAnimals, WordList};
+
+    /// Validates word lists like [`GoofyAnimals::new_with_adjective_count`], panicking
+    /// at compile time for invalid lists.
+    pub const fn goofy_animals<'a>(
+        animals: WordList<'a>,
+        adjectives: WordList<'a>,
+        adjective_count: usize,
+    ) -> GoofyAnimals<'a> {
+        match crate::validation::validate_const(animals, adjectives, adjective_count) {
+            Ok(()) => GoofyAnimals::from_word_lists(animals, adjectives, adjective_count),
+            Err(error) => panic!("{}", error.panic_message()),
+        }
+    }
+
+    /// Removes a single final newline, so files ending with one don't produce a
+    /// trailing empty word.
+    pub const fn strip_final_newline(text: &str) -> &str {
+        match text.as_bytes() {
+            [.., b'\n'] => text.split_at(text.len() - 1).0,
+            _ => text,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::__private::strip_final_newline;
+    use crate::{DEFAULT_GOOFY_ANIMALS, GoofyAnimals};
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn final_newline() {
+        assert_eq!(strip_final_newline("moth\nowl\n"), "moth\nowl");
+        assert_eq!(strip_final_newline("moth\nowl"), "moth\nowl");
+        assert_eq!(strip_final_newline("moth\n\n"), "moth\n");
+        assert_eq!(strip_final_newline(""), "");
+    }
+
+    #[test]
+    #[cfg(feature = "packed-words")]
+    fn packed_words() {
+        use super::{word_count, word_offsets};
+        use crate::WordList;
+
+        const TEXT: &str = "moth\npolar bear\nowl";
+        const OFFSETS: [u16; word_count(TEXT) + 1] = word_offsets(TEXT);
+
+        assert_eq!(OFFSETS, [0, 5, 16, 20]);
+        assert_eq!(
+            WordList::from_packed(TEXT, &OFFSETS),
+            WordList::from_slice(&["moth", "polar bear", "owl"])
+        );
+
+        const EMPTY: [u16; word_count("") + 1] = word_offsets("");
+        assert_eq!(WordList::from_packed("", &EMPTY).get(0), Some(""));
+    }
+
+    #[test]
+    fn adjective_count() {
+        const SHORT: GoofyAnimals<'static> = goofy_animals!(
+            animals = "data/en_animals.txt",
+            adjectives = "data/en_adjectives.txt",
+            adjective_count = 1,
+        );
+
+        assert_eq!(SHORT.adjective_count(), 1);
+        assert_eq!(
+            SHORT.combinations(),
+            DEFAULT_GOOFY_ANIMALS.with_adjective_count(1).combinations()
+        );
+    }
+
+    #[test]
+    fn slices() {
+        // Only the built-in word lists are packed, whatever the features
+        const ANIMALS: GoofyAnimals<'static> = goofy_animals!(
+            animals = "data/en_animals.txt",
+            adjectives = "data/en_adjectives.txt",
+        );
+
+        assert_eq!(ANIMALS.get_animals().len(), 355);
+        assert_eq!(ANIMALS.get_adjectives().len(), 1300);
+        assert_eq!(
+            DEFAULT_GOOFY_ANIMALS.animal_list().as_slice().is_none(),
+            cfg!(feature = "packed-words")
+        );
+    }
+}
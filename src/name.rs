@@ -0,0 +1,137 @@
+use core::fmt::{Display, Formatter};
+
+use crate::{GoofyAnimals, MAX_ADJECTIVES, NameStyle, StyledName};
+
+/// A generated goofy name in `adjective-adjective-animal` form.
+///
+/// The number of adjectives depends on the [`GoofyAnimals`] instance the name was
+/// generated from, see [`GoofyAnimals::adjective_count`].
+///
+/// Besides the words themselves, a `GoofyName` remembers the positions of the
+/// words in the lists they were drawn from, so it can be stored compactly and
+/// compared, ordered or hashed without going through a string.
+///
+/// # Examples
+///
+/// ```rust
+/// use rand::SeedableRng;
+/// use rand_chacha::ChaCha20Rng;
+/// use goofy_animals::DEFAULT_GOOFY_ANIMALS;
+///
+/// let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
+/// let name = DEFAULT_GOOFY_ANIMALS.generate_name_parts(&mut rng);
+/// assert_eq!(name.adjectives(), ["dismal", "outlying"]);
+/// assert_eq!(name.animal(), "moth");
+/// assert_eq!(name.to_string(), "dismal-outlying-moth");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GoofyName<'a> {
+    adjectives: [&'a str; MAX_ADJECTIVES],
+    adjective_count: usize,
+    animal: &'a str,
+    adjective_indices: [usize; MAX_ADJECTIVES],
+    animal_index: usize,
+}
+
+impl<'a> GoofyName<'a> {
+    /// Looks up the words at the given positions in the word lists of `animals`.
+    pub(crate) fn from_indices(
+        animals: &GoofyAnimals<'a>,
+        adjective_indices: &[usize],
+        animal_index: usize,
+    ) -> Self {
+        let mut adjectives = [""; MAX_ADJECTIVES];
+        let mut indices = [0; MAX_ADJECTIVES];
+        for (slot, &index) in adjective_indices.iter().enumerate() {
+            adjectives[slot] = animals.adjective_list().word(index);
+            indices[slot] = index;
+        }
+
+        Self {
+            adjectives,
+            adjective_count: adjective_indices.len(),
+            animal: animals.animal_list().word(animal_index),
+            adjective_indices: indices,
+            animal_index,
+        }
+    }
+
+    /// Returns the adjectives of this name, in order.
+    pub fn adjectives(&self) -> &[&'a str] {
+        &self.adjectives[..self.adjective_count]
+    }
+
+    /// Returns the animal of this name.
+    pub fn animal(&self) -> &'a str {
+        self.animal
+    }
+
+    /// Returns the positions of the adjectives in the adjective list they were drawn from.
+    pub fn adjective_indices(&self) -> &[usize] {
+        &self.adjective_indices[..self.adjective_count]
+    }
+
+    /// Returns the position of the animal in the animal list it was drawn from.
+    pub fn animal_index(&self) -> usize {
+        self.animal_index
+    }
+
+    /// Returns a value displaying this name in the given style.
+    ///
+    /// Displaying a `GoofyName` directly uses [`NameStyle::Kebab`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rand::SeedableRng;
+    /// use rand_chacha::ChaCha20Rng;
+    /// use goofy_animals::{DEFAULT_GOOFY_ANIMALS, NameStyle};
+    ///
+    /// let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
+    /// let name = DEFAULT_GOOFY_ANIMALS.generate_name_parts(&mut rng);
+    /// assert_eq!(name.styled(NameStyle::Pascal).to_string(), "DismalOutlyingMoth");
+    /// ```
+    pub fn styled(&self, style: NameStyle) -> StyledName<'_, 'a> {
+        StyledName::new(self, style)
+    }
+}
+
+impl Display for GoofyName<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.styled(NameStyle::Kebab).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::GoofyName;
+    use crate::GoofyAnimals;
+
+    use pretty_assertions::assert_eq;
+
+    const ANIMALS: GoofyAnimals<'static> = GoofyAnimals::new(
+        &["ant", "moth"],
+        &["able", "big", "dismal", "outrageous", "zany"],
+    );
+
+    #[test]
+    fn display() {
+        let name = GoofyName::from_indices(&ANIMALS, &[2, 3], 1);
+        assert_eq!(::alloc::format!("{name}"), "dismal-outrageous-moth");
+
+        let name = GoofyName::from_indices(&ANIMALS, &[], 1);
+        assert_eq!(::alloc::format!("{name}"), "moth");
+    }
+
+    #[test]
+    fn ordering() {
+        let one = GoofyName::from_indices(&ANIMALS, &[0, 4], 1);
+        let two = GoofyName::from_indices(&ANIMALS, &[1, 0], 0);
+
+        assert!(one < two);
+        assert_eq!(one, one);
+        assert_eq!(one.adjectives(), ["able", "zany"]);
+        assert_eq!(one.adjective_indices(), [0, 4]);
+        assert_eq!(two.animal_index(), 0);
+    }
+}
@@ -0,0 +1,571 @@
+use core::fmt::{Debug, Formatter};
+
+use ::alloc::collections::BTreeSet;
+use ::alloc::string::String;
+use ::alloc::vec::Vec;
+
+use crate::{GoofyAnimals, WordList, WordListError};
+
+/// Word lists owned by the generator, for lists only known at runtime.
+///
+/// [`GoofyAnimals`] borrows its word lists, which suits lists built at compile time
+/// but not lists read from a configuration file. `GoofyAnimalsBuf` owns its words
+/// and hands out a borrowed [`GoofyAnimals`] view with
+/// [`GoofyAnimalsBuf::as_goofy_animals`], which generates names like any other
+/// instance.
+///
+/// The word lists are checked on construction with the same rules as
+/// [`GoofyAnimals::try_new`].
+///
+/// # Examples
+///
+/// ```rust
+/// use rand::SeedableRng;
+/// use rand_chacha::ChaCha20Rng;
+/// use goofy_animals::GoofyAnimalsBuf;
+///
+/// let animals = GoofyAnimalsBuf::from_text("moth\npolar bear\n", "dismal\noutrageous\n").unwrap();
+///
+/// let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
+/// let name = animals.as_goofy_animals().generate_name(&mut rng);
+/// assert!(animals.as_goofy_animals().index_of(&name).is_some());
+/// ```
+///
+/// # Feature Flag
+///
+/// This type is only available when the `alloc` feature is enabled.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct GoofyAnimalsBuf {
+    animals: Vec<String>,
+    adjectives: Vec<String>,
+    adjective_count: usize,
+}
+
+impl GoofyAnimalsBuf {
+    /// Creates a new `GoofyAnimalsBuf` owning the given animal and adjective lists.
+    ///
+    /// Names generated from this instance have two adjectives.
+    ///
+    /// # Arguments
+    ///
+    /// * `animals` - The animal names
+    /// * `adjectives` - The adjectives
+    ///
+    /// # Returns
+    ///
+    /// A new `GoofyAnimalsBuf` instance, or a [`WordListError`] describing the first
+    /// problem found in the word lists.
+    pub fn try_new(animals: Vec<String>, adjectives: Vec<String>) -> Result<Self, WordListError> {
+        Self::try_new_with_adjective_count(animals, adjectives, 2)
+    }
+
+    /// Creates a new `GoofyAnimalsBuf` owning the given animal and adjective lists,
+    /// generating names with `adjective_count` adjectives.
+    ///
+    /// # Arguments
+    ///
+    /// * `animals` - The animal names
+    /// * `adjectives` - The adjectives
+    /// * `adjective_count` - The number of adjectives per name, up to [`MAX_ADJECTIVES`](crate::MAX_ADJECTIVES)
+    ///
+    /// # Returns
+    ///
+    /// A new `GoofyAnimalsBuf` instance, or a [`WordListError`] describing the first
+    /// problem found in the word lists.
+    pub fn try_new_with_adjective_count(
+        animals: Vec<String>,
+        adjectives: Vec<String>,
+        adjective_count: usize,
+    ) -> Result<Self, WordListError> {
+        GoofyAnimals::try_from_word_lists(
+            WordList::from_strings(&animals),
+            WordList::from_strings(&adjectives),
+            adjective_count,
+        )?;
+
+        Ok(Self {
+            animals,
+            adjectives,
+            adjective_count,
+        })
+    }
+
+    /// Creates a new `GoofyAnimalsBuf` from iterators of words.
+    ///
+    /// Names generated from this instance have two adjectives.
+    ///
+    /// # Arguments
+    ///
+    /// * `animals` - The animal names
+    /// * `adjectives` - The adjectives
+    ///
+    /// # Returns
+    ///
+    /// A new `GoofyAnimalsBuf` instance, or a [`WordListError`] describing the first
+    /// problem found in the word lists.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use goofy_animals::{DEFAULT_GOOFY_ANIMALS, GoofyAnimalsBuf};
+    ///
+    /// let short = GoofyAnimalsBuf::from_words(
+    ///     DEFAULT_GOOFY_ANIMALS.animal_list(),
+    ///     DEFAULT_GOOFY_ANIMALS.adjective_list().iter().filter(|word| word.len() < 5),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(short.as_goofy_animals().animal_list().len(), 355);
+    /// ```
+    pub fn from_words<A, B>(animals: A, adjectives: B) -> Result<Self, WordListError>
+    where
+        A: IntoIterator,
+        A::Item: Into<String>,
+        B: IntoIterator,
+        B::Item: Into<String>,
+    {
+        Self::try_new(
+            animals.into_iter().map(Into::into).collect(),
+            adjectives.into_iter().map(Into::into).collect(),
+        )
+    }
+
+    /// Creates a new `GoofyAnimalsBuf` from newline-delimited text.
+    ///
+    /// The text holds one word per line, in the same format as the built-in word
+    /// lists. A final newline is allowed, but any other empty line is rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `animals` - The animal names, one per line
+    /// * `adjectives` - The adjectives, one per line
+    ///
+    /// # Returns
+    ///
+    /// A new `GoofyAnimalsBuf` instance, or a [`WordListError`] describing the first
+    /// problem found in the word lists. The index of an offending word is its line
+    /// number, counting from zero.
+    pub fn from_text(animals: &str, adjectives: &str) -> Result<Self, WordListError> {
+        Self::from_words(lines(animals), lines(adjectives))
+    }
+
+    /// Returns a copy of this instance generating names with `adjective_count` adjectives.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `adjective_count` is larger than
+    /// [`MAX_ADJECTIVES`](crate::MAX_ADJECTIVES) or than the number of adjectives.
+    pub fn with_adjective_count(self, adjective_count: usize) -> Self {
+        // Checks the count against the word lists
+        self.as_goofy_animals()
+            .with_adjective_count(adjective_count);
+
+        Self {
+            adjective_count,
+            ..self
+        }
+    }
+
+    /// Adds the words of `other` to the word lists of this instance.
+    ///
+    /// Words already in a list are skipped, and the words of this instance come first.
+    /// The adjectives per name are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The instance whose animals and adjectives are added
+    ///
+    /// # Returns
+    ///
+    /// The combined instance, or a [`WordListError`] if the combined word lists are
+    /// invalid.
+    pub fn union(self, other: GoofyAnimals<'_>) -> Result<Self, WordListError> {
+        let Self {
+            animals,
+            adjectives,
+            adjective_count,
+        } = self;
+
+        Self::try_new_with_adjective_count(
+            union(animals, other.animal_list()),
+            union(adjectives, other.adjective_list()),
+            adjective_count,
+        )
+    }
+
+    /// Adds animals to this instance, skipping those already in the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `animals` - The animal names to add
+    ///
+    /// # Returns
+    ///
+    /// The extended instance, or a [`WordListError`] if one of the new words is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use goofy_animals::{DEFAULT_GOOFY_ANIMALS, GoofyAnimalsBuf};
+    ///
+    /// let animals = GoofyAnimalsBuf::from(DEFAULT_GOOFY_ANIMALS)
+    ///     .union_animals(["ferris", "moth"])
+    ///     .unwrap();
+    /// assert_eq!(animals.as_goofy_animals().animal_list().len(), 356);
+    /// ```
+    pub fn union_animals<I>(self, animals: I) -> Result<Self, WordListError>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self::try_new_with_adjective_count(
+            union(self.animals, animals),
+            self.adjectives,
+            self.adjective_count,
+        )
+    }
+
+    /// Adds adjectives to this instance, skipping those already in the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `adjectives` - The adjectives to add
+    ///
+    /// # Returns
+    ///
+    /// The extended instance, or a [`WordListError`] if one of the new words is invalid.
+    pub fn union_adjectives<I>(self, adjectives: I) -> Result<Self, WordListError>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self::try_new_with_adjective_count(
+            self.animals,
+            union(self.adjectives, adjectives),
+            self.adjective_count,
+        )
+    }
+
+    /// Removes every word of `denylist` from both word lists.
+    ///
+    /// # Arguments
+    ///
+    /// * `denylist` - The words to remove
+    ///
+    /// # Returns
+    ///
+    /// The remaining instance, or a [`WordListError`] if too few words are left.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use goofy_animals::{DEFAULT_GOOFY_ANIMALS, GoofyAnimalsBuf};
+    ///
+    /// assert!(DEFAULT_GOOFY_ANIMALS.index_of("idiotic-dismal-moth").is_some());
+    ///
+    /// let animals = GoofyAnimalsBuf::from(DEFAULT_GOOFY_ANIMALS)
+    ///     .difference(["idiotic", "louse"])
+    ///     .unwrap();
+    /// assert_eq!(animals.as_goofy_animals().index_of("idiotic-dismal-moth"), None);
+    /// assert_eq!(animals.as_goofy_animals().index_of("dismal-educated-louse"), None);
+    /// ```
+    pub fn difference<I>(self, denylist: I) -> Result<Self, WordListError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let denylist: BTreeSet<String> = denylist
+            .into_iter()
+            .map(|word| word.as_ref().into())
+            .collect();
+
+        self.filter_animals(|word| !denylist.contains(word))?
+            .filter_adjectives(|word| !denylist.contains(word))
+    }
+
+    /// Keeps only the animals for which `predicate` returns `true`.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - Decides whether an animal is kept
+    ///
+    /// # Returns
+    ///
+    /// The filtered instance, or a [`WordListError`] if no animals are left.
+    pub fn filter_animals(
+        self,
+        mut predicate: impl FnMut(&str) -> bool,
+    ) -> Result<Self, WordListError> {
+        let Self {
+            mut animals,
+            adjectives,
+            adjective_count,
+        } = self;
+
+        animals.retain(|word| predicate(word));
+        Self::try_new_with_adjective_count(animals, adjectives, adjective_count)
+    }
+
+    /// Keeps only the adjectives for which `predicate` returns `true`.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - Decides whether an adjective is kept
+    ///
+    /// # Returns
+    ///
+    /// The filtered instance, or a [`WordListError`] if fewer adjectives than
+    /// adjectives per name are left.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use goofy_animals::{DEFAULT_GOOFY_ANIMALS, GoofyAnimalsBuf};
+    ///
+    /// let animals = GoofyAnimalsBuf::from(DEFAULT_GOOFY_ANIMALS)
+    ///     .filter_adjectives(|word| word.len() < 8)
+    ///     .unwrap();
+    /// assert!(animals.as_goofy_animals().adjective_list().iter().all(|word| word.len() < 8));
+    /// ```
+    pub fn filter_adjectives(
+        self,
+        mut predicate: impl FnMut(&str) -> bool,
+    ) -> Result<Self, WordListError> {
+        let Self {
+            animals,
+            mut adjectives,
+            adjective_count,
+        } = self;
+
+        adjectives.retain(|word| predicate(word));
+        Self::try_new_with_adjective_count(animals, adjectives, adjective_count)
+    }
+
+    /// Returns a [`GoofyAnimals`] instance borrowing the word lists of this one.
+    ///
+    /// The view is cheap to create and copy, so there's no need to keep it around.
+    pub fn as_goofy_animals(&self) -> GoofyAnimals<'_> {
+        GoofyAnimals::from_word_lists(
+            WordList::from_strings(&self.animals),
+            WordList::from_strings(&self.adjectives),
+            self.adjective_count,
+        )
+    }
+
+    /// Returns the number of adjectives in each generated name.
+    pub fn adjective_count(&self) -> usize {
+        self.adjective_count
+    }
+
+    /// Consumes this instance, returning the animal and adjective lists.
+    pub fn into_parts(self) -> (Vec<String>, Vec<String>) {
+        (self.animals, self.adjectives)
+    }
+}
+
+impl<'a> From<&'a GoofyAnimalsBuf> for GoofyAnimals<'a> {
+    fn from(animals: &'a GoofyAnimalsBuf) -> Self {
+        animals.as_goofy_animals()
+    }
+}
+
+impl From<GoofyAnimals<'_>> for GoofyAnimalsBuf {
+    fn from(animals: GoofyAnimals<'_>) -> Self {
+        Self {
+            animals: animals.animal_list().iter().map(Into::into).collect(),
+            adjectives: animals.adjective_list().iter().map(Into::into).collect(),
+            adjective_count: animals.adjective_count(),
+        }
+    }
+}
+
+impl Debug for GoofyAnimalsBuf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GoofyAnimalsBuf")
+            .field("total_adjectives", &self.adjectives.len())
+            .field("total_animals", &self.animals.len())
+            .field("adjective_count", &self.adjective_count)
+            .finish()
+    }
+}
+
+/// Appends the words of `extra` missing from `words`.
+fn union<I>(mut words: Vec<String>, extra: I) -> Vec<String>
+where
+    I: IntoIterator,
+    I::Item: Into<String>,
+{
+    let mut seen: BTreeSet<String> = words.iter().cloned().collect();
+
+    for word in extra {
+        let word = word.into();
+        if seen.insert(word.clone()) {
+            words.push(word);
+        }
+    }
+
+    words
+}
+
+/// Splits newline-delimited text into its lines, ignoring a final newline.
+fn lines(text: &str) -> impl Iterator<Item = &str> {
+    let text = text.strip_suffix('\n').unwrap_or(text);
+
+    // Empty text has no lines rather than a single empty one
+    text.split('\n').filter(move |_| !text.is_empty())
+}
+
+#[cfg(test)]
+mod test {
+    use super::GoofyAnimalsBuf;
+    use crate::{DEFAULT_GOOFY_ANIMALS, GoofyAnimals, WordListError, WordListKind};
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn from_text() {
+        let animals = GoofyAnimalsBuf::from_text(
+            include_str!("data/en_animals.txt"),
+            include_str!("data/en_adjectives.txt"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            animals.as_goofy_animals().animal_list(),
+            DEFAULT_GOOFY_ANIMALS.animal_list()
+        );
+        assert_eq!(
+            animals.as_goofy_animals().adjective_list(),
+            DEFAULT_GOOFY_ANIMALS.adjective_list()
+        );
+
+        let with_newline = GoofyAnimalsBuf::from_text("cat\ndog\n", "big\nred").unwrap();
+        assert_eq!(
+            with_newline,
+            GoofyAnimalsBuf::from_words(["cat", "dog"], ["big", "red"]).unwrap()
+        );
+
+        assert_eq!(
+            GoofyAnimalsBuf::from_text("", "big\nred"),
+            Err(WordListError::EmptyAnimals)
+        );
+        assert_eq!(
+            GoofyAnimalsBuf::from_text("cat\n\n", "big\nred"),
+            Err(WordListError::TrailingEmptyWord {
+                list: WordListKind::Animals,
+                index: 1
+            })
+        );
+        assert_eq!(
+            GoofyAnimalsBuf::from_text("cat\r\ndog", "big\nred"),
+            Err(WordListError::CarriageReturn {
+                list: WordListKind::Animals,
+                index: 0
+            })
+        );
+    }
+
+    #[test]
+    fn name_generation() {
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha20Rng;
+
+        let animals = GoofyAnimalsBuf::from_words(
+            DEFAULT_GOOFY_ANIMALS.animal_list(),
+            DEFAULT_GOOFY_ANIMALS.adjective_list(),
+        )
+        .unwrap();
+
+        let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
+        assert_eq!(
+            animals.as_goofy_animals().generate_name(&mut rng),
+            "dismal-outlying-moth"
+        );
+
+        let short = animals.with_adjective_count(1);
+        assert_eq!(short.adjective_count(), 1);
+        assert_eq!(short.as_goofy_animals().combinations(), 1300 * 355);
+    }
+
+    #[test]
+    fn combinators() {
+        let animals = GoofyAnimalsBuf::from_words(["cat", "dog"], ["big", "red", "shy"]).unwrap();
+
+        let union = animals
+            .clone()
+            .union(GoofyAnimals::new(&["owl", "cat"], &["wet", "big"]))
+            .unwrap();
+        assert_eq!(
+            union,
+            GoofyAnimalsBuf::from_words(["cat", "dog", "owl"], ["big", "red", "shy", "wet"])
+                .unwrap()
+        );
+
+        assert_eq!(
+            animals.clone().union_animals(["Owl"]),
+            Err(WordListError::Uppercase {
+                list: WordListKind::Animals,
+                index: 2
+            })
+        );
+
+        assert_eq!(
+            animals
+                .clone()
+                .difference(["dog", "red", "unicorn"])
+                .unwrap(),
+            GoofyAnimalsBuf::from_words(["cat"], ["big", "shy"]).unwrap()
+        );
+        assert_eq!(
+            animals.clone().difference(["cat", "dog"]),
+            Err(WordListError::EmptyAnimals)
+        );
+
+        assert_eq!(
+            animals
+                .clone()
+                .with_adjective_count(3)
+                .filter_adjectives(|word| word != "shy"),
+            Err(WordListError::TooFewAdjectives {
+                required: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            animals
+                .filter_animals(|word| word.starts_with('c'))
+                .unwrap()
+                .as_goofy_animals()
+                .animal_list(),
+            GoofyAnimals::new(&["cat"], &["big", "red"]).animal_list()
+        );
+    }
+
+    #[test]
+    fn from_goofy_animals() {
+        let animals = GoofyAnimalsBuf::from(DEFAULT_GOOFY_ANIMALS.with_adjective_count(3));
+
+        assert_eq!(animals.adjective_count(), 3);
+        assert_eq!(
+            animals.as_goofy_animals().combinations(),
+            DEFAULT_GOOFY_ANIMALS.with_adjective_count(3).combinations()
+        );
+    }
+
+    #[test]
+    fn adjective_counts() {
+        let adjectives = ::alloc::vec!["big".into(), "red".into()];
+
+        assert_eq!(
+            GoofyAnimalsBuf::try_new_with_adjective_count(
+                ::alloc::vec!["cat".into()],
+                adjectives,
+                3
+            ),
+            Err(WordListError::TooFewAdjectives {
+                required: 3,
+                found: 2
+            })
+        );
+    }
+}
@@ -0,0 +1,412 @@
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use crate::{DEFAULT_GOOFY_ANIMALS, GoofyAnimals, GoofyName, MAX_ADJECTIVES, WordList, order_key};
+
+/// The reason a name couldn't be parsed by [`GoofyAnimals::parse_name`].
+///
+/// Offsets are byte offsets into the parsed name, and adjective positions count
+/// from zero.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseNameError {
+    /// The name doesn't have one animal and
+    /// [`GoofyAnimals::adjective_count`] adjectives: `expected` counts the
+    /// adjectives plus the animal, `found` the words the name was made of.
+    WrongArity { expected: usize, found: usize },
+    /// The word at the given byte offset, expected to be the adjective at
+    /// `position`, isn't in the adjective list.
+    UnknownAdjective { position: usize, offset: usize },
+    /// The words from the given byte offset on aren't in the animal list.
+    UnknownAnimal { offset: usize },
+    /// The adjective at `position` is the same as the one at `first`.
+    RepeatedAdjective { position: usize, first: usize },
+}
+
+impl<'a> GoofyAnimals<'a> {
+    /// Parses a name back into its parts.
+    ///
+    /// Parsing is tolerant of the style of the name: words are compared ignoring
+    /// case, and are separated by any non-alphanumeric character or a lowercase to
+    /// uppercase transition, so every [`NameStyle`](crate::NameStyle) is accepted.
+    /// Multi-word entries such as `polar bear` match their words in a row. Every way
+    /// to split the words into entries is tried, so `big red moth` parses with the
+    /// adjectives `big` and `red` even when `big red` is an adjective too. When
+    /// several readings are names, the one generated by
+    /// [`GoofyAnimals::nth_name`] at the lowest position is returned, as by
+    /// [`GoofyAnimals::index_of`].
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name to parse
+    ///
+    /// # Returns
+    ///
+    /// A [`GoofyName`] holding the matched words and their positions in the word
+    /// lists, or a [`ParseNameError`] describing why `name` isn't a name this
+    /// instance could have generated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use goofy_animals::{DEFAULT_GOOFY_ANIMALS, ParseNameError};
+    ///
+    /// let name = DEFAULT_GOOFY_ANIMALS.parse_name("OutlyingHauntingPolarBear").unwrap();
+    /// assert_eq!(name.adjectives(), ["outlying", "haunting"]);
+    /// assert_eq!(name.animal(), "polar bear");
+    /// assert_eq!(DEFAULT_GOOFY_ANIMALS.parse_name("outlying_haunting_polar_bear"), Ok(name));
+    ///
+    /// assert_eq!(
+    ///     DEFAULT_GOOFY_ANIMALS.parse_name("outlying-outlying-moth"),
+    ///     Err(ParseNameError::RepeatedAdjective { position: 1, first: 0 })
+    /// );
+    /// assert_eq!(
+    ///     DEFAULT_GOOFY_ANIMALS.parse_name("outlying-moth"),
+    ///     Err(ParseNameError::WrongArity { expected: 3, found: 2 })
+    /// );
+    /// ```
+    pub fn parse_name(&self, name: &str) -> Result<GoofyName<'a>, ParseNameError> {
+        let mut first = None;
+        self.each_reading(
+            Words::new(name),
+            &|list, words, found| {
+                for (index, entry) in self.word_list(list).iter().enumerate() {
+                    if let Some(rest) = match_words(entry, words.clone()) {
+                        found(index, rest);
+                    }
+                }
+            },
+            &mut |adjectives, animal, mut rest| {
+                if rest.next().is_some() {
+                    return;
+                }
+
+                let key = order_key(adjectives, animal);
+                if first.is_none_or(|first| key < first) {
+                    first = Some(key);
+                }
+            },
+        );
+
+        match first {
+            Some((adjectives, animal)) => Ok(GoofyName::from_indices(
+                self,
+                &adjectives[..self.adjective_count],
+                animal,
+            )),
+            None => Err(self.parse_error(name)),
+        }
+    }
+
+    /// Explains why `name` has no reading as a name, matching the longest entries
+    /// from the start of the name to find the first problem.
+    fn parse_error(&self, name: &str) -> ParseNameError {
+        let expected = self.adjective_count + 1;
+        let mut words = Words::new(name);
+
+        let mut adjectives = [0; MAX_ADJECTIVES];
+        for position in 0..self.adjective_count {
+            let Some((adjective, rest)) = longest_match(self.adjectives, words.clone()) else {
+                // A name with fewer adjectives ends with its animal here
+                if words.offset() == name.len()
+                    || exact_match(self.animals, words.clone()).is_some()
+                {
+                    return ParseNameError::WrongArity {
+                        expected,
+                        found: position + usize::from(words.offset() < name.len()),
+                    };
+                }
+
+                return ParseNameError::UnknownAdjective {
+                    position,
+                    offset: words.offset(),
+                };
+            };
+
+            if let Some(first) = adjectives[..position].iter().position(|&a| a == adjective) {
+                return ParseNameError::RepeatedAdjective { position, first };
+            }
+
+            adjectives[position] = adjective;
+            words = rest;
+        }
+
+        let offset = words.offset();
+        if offset == name.len() {
+            return ParseNameError::WrongArity {
+                expected,
+                found: self.adjective_count,
+            };
+        }
+
+        // A name with more adjectives continues with them before its animal
+        let mut found = expected;
+        while let Some((_, rest)) = longest_match(self.adjectives, words.clone()) {
+            found += 1;
+            words = rest;
+
+            if exact_match(self.animals, words.clone()).is_some() {
+                return ParseNameError::WrongArity { expected, found };
+            }
+        }
+
+        ParseNameError::UnknownAnimal { offset }
+    }
+}
+
+/// Parses a name made of the default word lists, see [`GoofyAnimals::parse_name`].
+///
+/// # Examples
+///
+/// ```rust
+/// use goofy_animals::GoofyName;
+///
+/// let name: GoofyName = "Outlying Haunting Firefly".parse().unwrap();
+/// assert_eq!(name.to_string(), "outlying-haunting-firefly");
+/// ```
+impl FromStr for GoofyName<'static> {
+    type Err = ParseNameError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        DEFAULT_GOOFY_ANIMALS.parse_name(name)
+    }
+}
+
+/// Parses a name made of the default word lists, see [`GoofyAnimals::parse_name`].
+impl TryFrom<&str> for GoofyName<'static> {
+    type Error = ParseNameError;
+
+    fn try_from(name: &str) -> Result<Self, Self::Error> {
+        DEFAULT_GOOFY_ANIMALS.parse_name(name)
+    }
+}
+
+/// The words of a name, split on non-alphanumeric characters and lowercase to
+/// uppercase transitions.
+#[derive(Clone)]
+pub(crate) struct Words<'n> {
+    name: &'n str,
+    position: usize,
+}
+
+impl<'n> Words<'n> {
+    pub(crate) fn new(name: &'n str) -> Self {
+        Self { name, position: 0 }
+    }
+
+    /// Returns the byte offset of the next word, or the length of the name if there
+    /// are no words left.
+    fn offset(&self) -> usize {
+        self.name[self.position..]
+            .find(char::is_alphanumeric)
+            .map_or(self.name.len(), |start| self.position + start)
+    }
+}
+
+impl<'n> Iterator for Words<'n> {
+    type Item = &'n str;
+
+    fn next(&mut self) -> Option<&'n str> {
+        let start = self.offset();
+        let rest = &self.name[start..];
+
+        let mut end = rest.len();
+        let mut previous_lowercase = false;
+        for (index, c) in rest.char_indices() {
+            if !c.is_alphanumeric() || (previous_lowercase && c.is_uppercase()) {
+                end = index;
+                break;
+            }
+
+            previous_lowercase = c.is_lowercase();
+        }
+
+        self.position = start + end;
+        (end > 0).then(|| &rest[..end])
+    }
+}
+
+/// Matches the words of a word list entry against the next words of a name,
+/// ignoring case.
+///
+/// Returns the words following the entry if all of its words matched.
+pub(crate) fn match_words<'n, W: Iterator<Item = &'n str>>(entry: &str, mut words: W) -> Option<W> {
+    let mut entry_words = Words::new(entry).peekable();
+    entry_words.peek()?;
+
+    for entry_word in entry_words {
+        let word = words.next()?;
+        if !word
+            .chars()
+            .flat_map(char::to_lowercase)
+            .eq(entry_word.chars())
+        {
+            return None;
+        }
+    }
+
+    Some(words)
+}
+
+/// Finds the entry of `list` matching the most of the next words of a name.
+///
+/// Returns the position of the entry and the words following it.
+fn longest_match<'n>(list: WordList<'_>, words: Words<'n>) -> Option<(usize, Words<'n>)> {
+    list.iter()
+        .enumerate()
+        .filter_map(|(index, entry)| Some((index, match_words(entry, words.clone())?)))
+        .max_by_key(|(_, rest)| rest.position)
+}
+
+/// Finds the entry of `list` made of exactly the remaining words of a name.
+fn exact_match(list: WordList<'_>, words: Words<'_>) -> Option<usize> {
+    list.iter().position(|entry| {
+        match_words(entry, words.clone()).is_some_and(|mut rest| rest.next().is_none())
+    })
+}
+
+impl Display for ParseNameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::WrongArity { expected, found } => {
+                write!(f, "expected a name of {expected} words, found {found}")
+            }
+            Self::UnknownAdjective { position, offset } => {
+                write!(
+                    f,
+                    "unknown word at offset {offset}, expected adjective {position}"
+                )
+            }
+            Self::UnknownAnimal { offset } => write!(f, "unknown animal at offset {offset}"),
+            Self::RepeatedAdjective { position, first } => {
+                write!(f, "adjective {position} repeats adjective {first}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParseNameError {}
+
+#[cfg(test)]
+mod test {
+    use super::ParseNameError;
+    use crate::fixtures::{ANIMALS, STYLES};
+    use crate::{DEFAULT_GOOFY_ANIMALS, GoofyName};
+
+    use pretty_assertions::assert_eq;
+
+    fn parse(name: &str) -> Result<(::alloc::vec::Vec<&str>, &str), ParseNameError> {
+        ANIMALS
+            .parse_name(name)
+            .map(|name| (name.adjectives().to_vec(), name.animal()))
+    }
+
+    #[test]
+    fn styles() {
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha20Rng;
+
+        let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
+        for _ in 0..100 {
+            let name = DEFAULT_GOOFY_ANIMALS.generate_name_parts(&mut rng);
+
+            for style in STYLES {
+                let styled = ::alloc::format!("{}", name.styled(style));
+                assert_eq!(styled.parse::<GoofyName>(), Ok(name), "{styled}");
+            }
+        }
+    }
+
+    #[test]
+    fn longest_match() {
+        assert_eq!(
+            parse("red-big-moth"),
+            Ok((::alloc::vec!["red", "big"], "moth"))
+        );
+        assert_eq!(
+            parse("big red-shy-polar-bear"),
+            Ok((::alloc::vec!["big red", "shy"], "polar bear"))
+        );
+        assert_eq!(
+            parse("  Shy__BIG-polarBear "),
+            Ok((::alloc::vec!["shy", "big"], "polar bear"))
+        );
+    }
+
+    #[test]
+    fn backtracking() {
+        // `big red` matches first, but only `big` and `red` leave an animal
+        assert_eq!(
+            parse("big-red-moth"),
+            Ok((::alloc::vec!["big", "red"], "moth"))
+        );
+        assert_eq!(
+            parse("BigRedPolarBear"),
+            Ok((::alloc::vec!["big", "red"], "polar bear"))
+        );
+        assert_eq!(
+            parse("big-red-shy-moth"),
+            Ok((::alloc::vec!["big red", "shy"], "moth"))
+        );
+
+        for position in [4, 5, 20] {
+            let name = ANIMALS.nth_name(position).unwrap();
+            assert_eq!(
+                ANIMALS.parse_name(&::alloc::format!("{name}")),
+                Ok(name),
+                "{name}"
+            );
+        }
+        assert_eq!(
+            GoofyName::try_from("outlying-haunting-polar-bear").map(|name| name.animal_index()),
+            Ok(246)
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            parse("big-big-moth"),
+            Err(ParseNameError::RepeatedAdjective {
+                position: 1,
+                first: 0
+            })
+        );
+        assert_eq!(
+            parse("big-tall-moth"),
+            Err(ParseNameError::UnknownAdjective {
+                position: 1,
+                offset: 4
+            })
+        );
+        assert_eq!(
+            parse("big-shy-unicorn"),
+            Err(ParseNameError::UnknownAnimal { offset: 8 })
+        );
+        assert_eq!(
+            parse("big-shy-moth-moth"),
+            Err(ParseNameError::UnknownAnimal { offset: 8 })
+        );
+
+        for (name, found) in [
+            ("", 0),
+            ("--", 0),
+            ("moth", 1),
+            ("big", 1),
+            ("big-moth", 2),
+            ("big-shy", 2),
+            ("big-shy-red-moth", 4),
+            ("big-shy-red-big-red-polar-bear", 5),
+        ] {
+            assert_eq!(
+                parse(name),
+                Err(ParseNameError::WrongArity { expected: 3, found }),
+                "{name}"
+            );
+        }
+
+        assert_eq!(
+            parse("big-tall-moth").unwrap_err().to_string(),
+            "unknown word at offset 4, expected adjective 1"
+        );
+    }
+}
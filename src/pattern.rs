@@ -0,0 +1,605 @@
+use core::fmt::{Display, Formatter, Write};
+
+use crate::sample::{sample_below, sample_index};
+use crate::{GoofyAnimals, RandomSource, WordList, nth_unused};
+
+/// The largest number of literal and slot segments a [`NamePattern`] can hold.
+pub const MAX_PATTERN_SEGMENTS: usize = 16;
+
+/// A template describing the shape of a name, such as `"{adj}_{animal}-{num:4}"`.
+///
+/// A pattern is made of literal text and slots enclosed in braces:
+/// - `{adj}` - an adjective from the [`GoofyAnimals`] instance
+/// - `{animal}` - an animal from the [`GoofyAnimals`] instance
+/// - `{num:N}` - `N` random decimal digits, with `N` in `1..=19`
+/// - `{hex:N}` - `N` random lowercase hexadecimal digits, with `N` in `1..=16`
+/// - `{anything_else}` - a word from the extra word list with that name
+///
+/// Slots drawing from the same word list never repeat a word within one name, just
+/// like the adjectives of [`GoofyAnimals::generate_name_parts`]. Literal braces are
+/// written as `{{` and `}}`.
+///
+/// Words of multi-word entries, like `polar bear`, are joined with the separator of
+/// the pattern: the first `-`, `_`, `.` or space in its literal text. A pattern
+/// without any keeps the space, so `"{adj}_{animal}"` renders `big_polar_bear` and
+/// `"{animal}"` renders `polar bear`.
+///
+/// Patterns are parsed once with [`NamePattern::parse`] and can then be rendered any
+/// number of times into a [`core::fmt::Write`] sink, without allocating.
+///
+/// # Examples
+///
+/// ```rust
+/// use rand::SeedableRng;
+/// use rand_chacha::ChaCha20Rng;
+/// use goofy_animals::{DEFAULT_GOOFY_ANIMALS, NamePattern};
+///
+/// let pattern = NamePattern::parse("{color}-{animal}-{hex:3}").unwrap();
+/// let colors: &[&str] = &["red", "green", "blue"];
+/// let lists = [("color", colors)];
+///
+/// let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
+/// let mut name = String::new();
+/// pattern.render(&DEFAULT_GOOFY_ANIMALS, &lists, &mut rng, &mut name).unwrap();
+/// assert_eq!(name, "green-guan-50a");
+///
+/// assert_eq!(
+///     pattern.combinations(&DEFAULT_GOOFY_ANIMALS, &lists),
+///     Ok(3 * 355 * 16 * 16 * 16),
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NamePattern<'p> {
+    pattern: &'p str,
+    segments: [Segment<'p>; MAX_PATTERN_SEGMENTS],
+    total_segments: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Segment<'p> {
+    Literal(&'p str),
+    Word(WordSource<'p>),
+    Number { digits: u32 },
+    Hex { digits: u32 },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum WordSource<'p> {
+    Adjectives,
+    Animals,
+    List(&'p str),
+}
+
+/// An error produced while parsing or rendering a [`NamePattern`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PatternError<'p> {
+    /// A `{` at the given byte offset is never closed.
+    UnclosedSlot { offset: usize },
+    /// A `}` at the given byte offset doesn't close a slot.
+    UnexpectedBrace { offset: usize },
+    /// The slot starting at the given byte offset has an empty or malformed name.
+    InvalidSlot { offset: usize },
+    /// The slot starting at the given byte offset has a missing or out of range width.
+    InvalidWidth { offset: usize },
+    /// The pattern has more than [`MAX_PATTERN_SEGMENTS`] segments.
+    TooManySegments,
+    /// No extra word list with this name was given.
+    UnknownList(&'p str),
+    /// The word list has fewer words than the pattern has slots for it.
+    NotEnoughWords(&'p str),
+    /// Writing to the output failed.
+    Format,
+}
+
+impl<'p> NamePattern<'p> {
+    /// Parses a pattern.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The pattern text, see [`NamePattern`] for the syntax
+    ///
+    /// # Returns
+    ///
+    /// The parsed pattern, or a [`PatternError`] pointing at the offending part of
+    /// the text.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use goofy_animals::{NamePattern, PatternError};
+    ///
+    /// assert!(NamePattern::parse("{adj}_{animal}-{num:4}").is_ok());
+    /// assert_eq!(
+    ///     NamePattern::parse("{adj}-{num:99}"),
+    ///     Err(PatternError::InvalidWidth { offset: 6 }),
+    /// );
+    /// ```
+    pub fn parse(pattern: &'p str) -> Result<Self, PatternError<'p>> {
+        let mut parsed = Self {
+            pattern,
+            segments: [Segment::Literal(""); MAX_PATTERN_SEGMENTS],
+            total_segments: 0,
+        };
+
+        let bytes = pattern.as_bytes();
+        let mut literal_start = 0;
+        let mut offset = 0;
+
+        while offset < bytes.len() {
+            match (bytes[offset], bytes.get(offset + 1)) {
+                (b'{', Some(b'{')) | (b'}', Some(b'}')) => {
+                    // Keep one of the two braces as part of the literal
+                    parsed.push_literal(&pattern[literal_start..=offset])?;
+                    offset += 2;
+                    literal_start = offset;
+                }
+                (b'{', _) => {
+                    parsed.push_literal(&pattern[literal_start..offset])?;
+
+                    let Some(length) = pattern[offset + 1..].find('}') else {
+                        return Err(PatternError::UnclosedSlot { offset });
+                    };
+
+                    let slot = &pattern[offset + 1..offset + 1 + length];
+                    parsed.push(Segment::parse_slot(slot, offset)?)?;
+
+                    offset += length + 2;
+                    literal_start = offset;
+                }
+                (b'}', _) => return Err(PatternError::UnexpectedBrace { offset }),
+                _ => offset += 1,
+            }
+        }
+
+        parsed.push_literal(&pattern[literal_start..])?;
+
+        Ok(parsed)
+    }
+
+    /// Returns the text this pattern was parsed from.
+    pub fn as_str(&self) -> &'p str {
+        self.pattern
+    }
+
+    fn push_literal(&mut self, text: &'p str) -> Result<(), PatternError<'p>> {
+        if text.is_empty() {
+            return Ok(());
+        }
+
+        self.push(Segment::Literal(text))
+    }
+
+    fn push(&mut self, segment: Segment<'p>) -> Result<(), PatternError<'p>> {
+        let slot = self
+            .segments
+            .get_mut(self.total_segments)
+            .ok_or(PatternError::TooManySegments)?;
+
+        *slot = segment;
+        self.total_segments += 1;
+
+        Ok(())
+    }
+
+    fn segments(&self) -> &[Segment<'p>] {
+        &self.segments[..self.total_segments]
+    }
+
+    /// Returns the character joining the words of multi-word entries.
+    fn separator(&self) -> char {
+        self.segments()
+            .iter()
+            .find_map(|segment| match segment {
+                Segment::Literal(text) => text.chars().find(|c| matches!(c, '-' | '_' | '.' | ' ')),
+                _ => None,
+            })
+            .unwrap_or(' ')
+    }
+
+    /// Counts the slots drawing from `source` before the segment at `position`.
+    fn earlier_slots(&self, source: WordSource<'p>, position: usize) -> usize {
+        self.segments()[..position]
+            .iter()
+            .filter(|segment| **segment == Segment::Word(source))
+            .count()
+    }
+
+    /// Returns the number of distinct names this pattern can render.
+    ///
+    /// # Arguments
+    ///
+    /// * `animals` - The adjectives and animals used for `{adj}` and `{animal}` slots
+    /// * `lists` - Extra word lists, by the slot name they're used for
+    ///
+    /// # Returns
+    ///
+    /// The size of the name space, saturated at `u64::MAX`, or an error if the word
+    /// lists don't fit the pattern.
+    pub fn combinations(
+        &self,
+        animals: &GoofyAnimals<'_>,
+        lists: &[(&str, &[&str])],
+    ) -> Result<u64, PatternError<'p>> {
+        self.check(animals, lists)?;
+
+        let mut total: u64 = 1;
+        for (position, segment) in self.segments().iter().enumerate() {
+            let choices = match *segment {
+                Segment::Literal(_) => 1,
+                Segment::Word(source) => {
+                    let words = source.resolve(animals, lists)?;
+                    (words.len() - self.earlier_slots(source, position)) as u64
+                }
+                Segment::Number { digits } => 10u64.pow(digits),
+                Segment::Hex { digits } => 16u64.saturating_pow(digits),
+            };
+
+            total = total.saturating_mul(choices);
+        }
+
+        Ok(total)
+    }
+
+    /// Makes sure every word list exists and is long enough for the pattern.
+    fn check(
+        &self,
+        animals: &GoofyAnimals<'_>,
+        lists: &[(&str, &[&str])],
+    ) -> Result<(), PatternError<'p>> {
+        for (position, segment) in self.segments().iter().enumerate() {
+            if let Segment::Word(source) = *segment {
+                let words = source.resolve(animals, lists)?;
+
+                if words.len() <= self.earlier_slots(source, position) {
+                    return Err(PatternError::NotEnoughWords(source.name()));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Renders a random name following this pattern.
+    ///
+    /// The word lists are checked before anything is drawn from `rng` or written to
+    /// `out`. Every call makes exactly one draw from `rng` per slot.
+    ///
+    /// # Arguments
+    ///
+    /// * `animals` - The adjectives and animals used for `{adj}` and `{animal}` slots
+    /// * `lists` - Extra word lists, by the slot name they're used for
+    /// * `rng` - A mutable reference to any random number generator that implements [`RandomSource`].
+    /// * `out` - The sink receiving the name
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` once the name has been written, or an error if the word lists don't
+    /// fit the pattern or `out` failed.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, animals, lists, rng, out), fields(pattern = self.pattern), level = tracing::Level::TRACE))]
+    pub fn render(
+        &self,
+        animals: &GoofyAnimals<'_>,
+        lists: &[(&str, &[&str])],
+        rng: &mut impl RandomSource,
+        out: &mut impl Write,
+    ) -> Result<(), PatternError<'p>> {
+        self.check(animals, lists)?;
+
+        let separator = self.separator();
+        let mut picks = [0; MAX_PATTERN_SEGMENTS];
+        for (position, segment) in self.segments().iter().enumerate() {
+            match *segment {
+                Segment::Literal(text) => out.write_str(text)?,
+                Segment::Word(source) => {
+                    let words = source.resolve(animals, lists)?;
+
+                    // Skip over the words already used by earlier slots of the same list
+                    let mut taken = [0; MAX_PATTERN_SEGMENTS];
+                    let mut total_taken = 0;
+                    for (earlier, pick) in self.segments()[..position].iter().zip(picks) {
+                        if *earlier == Segment::Word(source) {
+                            taken[total_taken] = pick;
+                            total_taken += 1;
+                        }
+                    }
+
+                    let rank = sample_index(rng, words.len() - total_taken);
+                    picks[position] = nth_unused(rank, &taken[..total_taken]);
+
+                    write_word(out, &words[picks[position]], separator)?;
+                }
+                Segment::Number { digits } => {
+                    let value = sample_below(rng, 10u64.pow(digits));
+                    write!(out, "{value:0width$}", width = digits as usize)?;
+                }
+                Segment::Hex { digits } => {
+                    let value = rng.next_u64() >> (u64::BITS - 4 * digits);
+                    write!(out, "{value:0width$x}", width = digits as usize)?;
+                }
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(picks = ?&picks[..self.total_segments], "rendered pattern");
+
+        Ok(())
+    }
+
+    /// Renders a random name following this pattern into a new string.
+    ///
+    /// See [`NamePattern::render`] for details.
+    ///
+    /// # Feature Flag
+    ///
+    /// This function is only available when the `alloc` feature is enabled.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn render_to_string(
+        &self,
+        animals: &GoofyAnimals<'_>,
+        lists: &[(&str, &[&str])],
+        rng: &mut impl RandomSource,
+    ) -> Result<::alloc::string::String, PatternError<'p>> {
+        let mut name = ::alloc::string::String::new();
+        self.render(animals, lists, rng, &mut name)?;
+
+        Ok(name)
+    }
+}
+
+/// Writes `word`, with the spaces between the words of a multi-word entry replaced by
+/// `separator`.
+fn write_word(out: &mut impl Write, word: &str, separator: char) -> core::fmt::Result {
+    for (position, part) in word.split(' ').enumerate() {
+        if position > 0 {
+            out.write_char(separator)?;
+        }
+
+        out.write_str(part)?;
+    }
+
+    Ok(())
+}
+
+impl Display for NamePattern<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.pattern)
+    }
+}
+
+impl<'p> Segment<'p> {
+    fn parse_slot(slot: &'p str, offset: usize) -> Result<Self, PatternError<'p>> {
+        let (name, width) = match slot.split_once(':') {
+            Some((name, width)) => (name, Some(width)),
+            None => (slot, None),
+        };
+
+        let valid_name =
+            !name.is_empty() && name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_');
+        if !valid_name {
+            return Err(PatternError::InvalidSlot { offset });
+        }
+
+        let digits = |max: u32| {
+            width
+                .and_then(|width| width.parse::<u32>().ok())
+                .filter(|digits| (1..=max).contains(digits))
+                .ok_or(PatternError::InvalidWidth { offset })
+        };
+
+        match (name, width) {
+            ("num", _) => Ok(Self::Number {
+                digits: digits(19)?,
+            }),
+            ("hex", _) => Ok(Self::Hex {
+                digits: digits(16)?,
+            }),
+            (_, Some(_)) => Err(PatternError::InvalidWidth { offset }),
+            ("adj", None) => Ok(Self::Word(WordSource::Adjectives)),
+            ("animal", None) => Ok(Self::Word(WordSource::Animals)),
+            (name, None) => Ok(Self::Word(WordSource::List(name))),
+        }
+    }
+}
+
+impl<'p> WordSource<'p> {
+    fn name(self) -> &'p str {
+        match self {
+            Self::Adjectives => "adj",
+            Self::Animals => "animal",
+            Self::List(name) => name,
+        }
+    }
+
+    fn resolve<'w>(
+        self,
+        animals: &GoofyAnimals<'w>,
+        lists: &[(&str, &'w [&'w str])],
+    ) -> Result<WordList<'w>, PatternError<'p>> {
+        match self {
+            Self::Adjectives => Ok(animals.adjective_list()),
+            Self::Animals => Ok(animals.animal_list()),
+            Self::List(name) => lists
+                .iter()
+                .find(|(list, _)| *list == name)
+                .map(|(_, words)| WordList::from_slice(words))
+                .ok_or(PatternError::UnknownList(name)),
+        }
+    }
+}
+
+impl From<core::fmt::Error> for PatternError<'_> {
+    fn from(_: core::fmt::Error) -> Self {
+        Self::Format
+    }
+}
+
+impl Display for PatternError<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnclosedSlot { offset } => write!(f, "unclosed slot at offset {offset}"),
+            Self::UnexpectedBrace { offset } => write!(f, "unexpected '}}' at offset {offset}"),
+            Self::InvalidSlot { offset } => write!(f, "invalid slot name at offset {offset}"),
+            Self::InvalidWidth { offset } => write!(f, "invalid slot width at offset {offset}"),
+            Self::TooManySegments => write!(f, "more than {MAX_PATTERN_SEGMENTS} segments"),
+            Self::UnknownList(name) => write!(f, "unknown word list '{name}'"),
+            Self::NotEnoughWords(name) => write!(f, "not enough words in word list '{name}'"),
+            Self::Format => write!(f, "failed to write name"),
+        }
+    }
+}
+
+impl core::error::Error for PatternError<'_> {}
+
+#[cfg(test)]
+mod test {
+    use super::{MAX_PATTERN_SEGMENTS, NamePattern, PatternError};
+    use crate::{DEFAULT_GOOFY_ANIMALS, GoofyAnimals, RandomFn};
+
+    use pretty_assertions::assert_eq;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    const COLORS: &[&str] = &["red", "green", "blue"];
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            NamePattern::parse("{adj"),
+            Err(PatternError::UnclosedSlot { offset: 0 })
+        );
+        assert_eq!(
+            NamePattern::parse("adj}"),
+            Err(PatternError::UnexpectedBrace { offset: 3 })
+        );
+        assert_eq!(
+            NamePattern::parse("{adj}-{}"),
+            Err(PatternError::InvalidSlot { offset: 6 })
+        );
+        assert_eq!(
+            NamePattern::parse("{a-b}"),
+            Err(PatternError::InvalidSlot { offset: 0 })
+        );
+        assert_eq!(
+            NamePattern::parse("{num}"),
+            Err(PatternError::InvalidWidth { offset: 0 })
+        );
+        assert_eq!(
+            NamePattern::parse("{hex:17}"),
+            Err(PatternError::InvalidWidth { offset: 0 })
+        );
+        assert_eq!(
+            NamePattern::parse("{animal:2}"),
+            Err(PatternError::InvalidWidth { offset: 0 })
+        );
+        assert_eq!(
+            NamePattern::parse(&"{adj}-".repeat(MAX_PATTERN_SEGMENTS)),
+            Err(PatternError::TooManySegments)
+        );
+    }
+
+    #[test]
+    fn render() {
+        let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
+        let lists = [("color", COLORS)];
+
+        let render = |pattern: &str, rng: &mut ChaCha20Rng| {
+            NamePattern::parse(pattern)
+                .unwrap()
+                .render_to_string(&DEFAULT_GOOFY_ANIMALS, &lists, rng)
+                .unwrap()
+        };
+
+        assert_eq!(
+            render("{adj}_{animal}-{num:4}", &mut rng),
+            "outlying_guan-3150"
+        );
+        assert_eq!(
+            render("{color}-{animal}-{hex:3}", &mut rng),
+            "red-gayal-853"
+        );
+        assert_eq!(
+            render("{adj}-{adj}-{animal}", &mut rng),
+            "glittering-relieved-booby"
+        );
+        assert_eq!(
+            render("{{{animal}}}-{num:19}-{hex:16}", &mut rng),
+            "{starfish}-0735785736806209764-8e24cab6bcb5bea0"
+        );
+        assert_eq!(render("plain", &mut rng), "plain");
+    }
+
+    #[test]
+    fn multi_word_entries() {
+        let animals = GoofyAnimals::new(&["polar bear"], &["big", "red"]);
+        let render = |pattern| {
+            NamePattern::parse(pattern)
+                .unwrap()
+                .render_to_string(&animals, &[], &mut RandomFn(|| 0))
+                .unwrap()
+        };
+
+        assert_eq!(render("{adj}_{animal}"), "big_polar_bear");
+        assert_eq!(render("{{{animal}}}-{adj}"), "{polar-bear}-big");
+        assert_eq!(render("{adj}.{adj} {animal}"), "big.red polar.bear");
+        assert_eq!(render("{animal}"), "polar bear");
+    }
+
+    #[test]
+    fn render_errors() {
+        let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
+        let pattern = NamePattern::parse("{color}-{color}-{animal}").unwrap();
+        let mut name = ::alloc::string::String::new();
+
+        assert_eq!(
+            pattern.render(&DEFAULT_GOOFY_ANIMALS, &[], &mut rng, &mut name),
+            Err(PatternError::UnknownList("color"))
+        );
+        assert_eq!(
+            pattern.render(
+                &DEFAULT_GOOFY_ANIMALS,
+                &[("color", &["red"])],
+                &mut rng,
+                &mut name
+            ),
+            Err(PatternError::NotEnoughWords("color"))
+        );
+        assert_eq!(name, "");
+    }
+
+    #[test]
+    fn distinct_words() {
+        let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
+        let animals = GoofyAnimals::new(&["cat", "dog"], &["big", "red"]);
+        let pattern = NamePattern::parse("{color}{color}{color}-{animal}{animal}").unwrap();
+        let lists = [("color", COLORS)];
+
+        assert_eq!(pattern.combinations(&animals, &lists), Ok(6 * 2));
+
+        for _ in 0..100 {
+            let name = pattern
+                .render_to_string(&animals, &lists, &mut rng)
+                .unwrap();
+            let (colors, animals) = name.split_once('-').unwrap();
+
+            assert_eq!(colors.len(), "redgreenblue".len());
+            assert!(animals == "catdog" || animals == "dogcat");
+        }
+    }
+
+    #[test]
+    fn combinations() {
+        let pattern = NamePattern::parse("{adj}-{adj}-{animal}").unwrap();
+        assert_eq!(
+            pattern.combinations(&DEFAULT_GOOFY_ANIMALS, &[]),
+            Ok(DEFAULT_GOOFY_ANIMALS.combinations())
+        );
+
+        let pattern = NamePattern::parse("{num:19}{hex:16}").unwrap();
+        assert_eq!(
+            pattern.combinations(&DEFAULT_GOOFY_ANIMALS, &[]),
+            Ok(u64::MAX)
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use core::cell::RefCell;
+
+use rand_chacha::ChaCha20Rng;
+use rand_chacha::rand_core::{RngCore, SeedableRng};
+
+use crate::{DEFAULT_GOOFY_ANIMALS, GoofyName, RandomSource};
+
+thread_local! {
+    /// The generator of the current thread, along with the process it was seeded in.
+    static THREAD_RNG: RefCell<Option<(u32, ThreadRng)>> = const { RefCell::new(None) };
+}
+
+/// The generator of a thread, which doesn't need the `rand` feature to be a
+/// [`RandomSource`].
+#[derive(Clone, Debug)]
+struct ThreadRng(ChaCha20Rng);
+
+impl RandomSource for ThreadRng {
+    fn next_u64(&mut self) -> u64 {
+        RngCore::next_u64(&mut self.0)
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        RngCore::next_u32(&mut self.0)
+    }
+}
+
+/// Returns the process the generator of the current thread has to be seeded in.
+///
+/// Only Unix can `fork` a process along with its thread-local generators, so only
+/// there is the process ID read, with a `getpid` call each time.
+fn current_process() -> u32 {
+    #[cfg(unix)]
+    return std::process::id();
+
+    #[cfg(not(unix))]
+    return 0;
+}
+
+/// Runs `f` with the generator of the current thread.
+///
+/// The generator is seeded from the operating system on first use. On Unix it's
+/// seeded again whenever the process ID changes, so a child process created with
+/// `fork` never repeats the names of its parent.
+fn with_thread_rng<T>(f: impl FnOnce(&mut ThreadRng) -> T) -> T {
+    THREAD_RNG.with_borrow_mut(|state| {
+        let pid = current_process();
+        state.take_if(|(seeded_in, _)| *seeded_in != pid);
+
+        let (_, rng) = state.get_or_insert_with(|| (pid, ThreadRng(ChaCha20Rng::from_os_rng())));
+        f(rng)
+    })
+}
+
+/// Generates the individual parts of a goofy name with a thread-local generator.
+///
+/// The generator is a ChaCha20 CSPRNG, lazily seeded from the operating system
+/// for each thread and reseeded in child processes after `fork`. To notice a `fork`,
+/// every call on Unix reads the process ID, which costs a `getpid` system call. Use
+/// [`generate_name_parts`](crate::generate_name_parts) with a seeded RNG instead for
+/// repeatable names, or to avoid that cost.
+///
+/// # Returns
+///
+/// A [`GoofyName`] from the default word lists.
+///
+/// # Examples
+///
+/// ```rust
+/// let name = goofy_animals::random_name_parts();
+/// assert_eq!(name.adjectives().len(), 2);
+/// ```
+///
+/// # Feature Flag
+///
+/// This function is only available when the `std` feature is enabled.
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn random_name_parts() -> GoofyName<'static> {
+    with_thread_rng(|rng| DEFAULT_GOOFY_ANIMALS.generate_name_parts(rng))
+}
+
+/// Generates a complete goofy name with a thread-local generator.
+///
+/// See [`random_name_parts`] for details about the generator.
+///
+/// # Returns
+///
+/// A `String` containing the generated name in the format `adjective-adjective-animal`.
+///
+/// # Examples
+///
+/// ```rust
+/// let name = goofy_animals::random_name();
+/// println!("{name}"); // e.g., "vigilant-troubled-firefly"
+/// ```
+///
+/// # Feature Flag
+///
+/// This function is only available when the `std` feature is enabled.
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn random_name() -> String {
+    with_thread_rng(|rng| DEFAULT_GOOFY_ANIMALS.generate_name(rng))
+}
+
+#[cfg(test)]
+mod test {
+    use rand_chacha::ChaCha20Rng;
+    use rand_chacha::rand_core::SeedableRng;
+
+    use super::{THREAD_RNG, ThreadRng, random_name, random_name_parts, with_thread_rng};
+    use crate::{DEFAULT_GOOFY_ANIMALS, RandomSource};
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn random_names() {
+        assert!(DEFAULT_GOOFY_ANIMALS.index_of(&random_name()).is_some());
+        assert_eq!(random_name_parts().adjectives().len(), 2);
+
+        let names = std::thread::spawn(|| (random_name(), random_name()))
+            .join()
+            .unwrap();
+        assert_ne!(names.0, names.1);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn reseeds_after_fork() {
+        let seeded = ThreadRng(ChaCha20Rng::seed_from_u64(0x1337));
+        let expected = seeded.clone().next_u64();
+
+        // The generator of the current process is kept
+        THREAD_RNG.set(Some((std::process::id(), seeded.clone())));
+        assert_eq!(with_thread_rng(|rng| rng.next_u64()), expected);
+
+        // One inherited from another process is replaced
+        THREAD_RNG.set(Some((std::process::id() ^ 1, seeded)));
+        assert_ne!(with_thread_rng(|rng| rng.next_u64()), expected);
+        assert_eq!(
+            THREAD_RNG.with_borrow(|state| state.as_ref().map(|(pid, _)| *pid)),
+            Some(std::process::id())
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use crate::RandomSource;
+
+/// Draws an integer in `0..bound`, distributed all but uniformly, from a single raw
+/// 64-bit word of `rng`.
+///
+/// Apart from the [`WordListVersion::V1`](crate::WordListVersion::V1) sampling
+/// method, every random choice made by this crate goes through this function, so the
+/// names generated from a seeded RNG only depend on the words returned by
+/// [`RandomSource::next_u64`] and never on the range sampling of a particular `rand`
+/// release.
+///
+/// # Algorithm
+///
+/// This is a widening multiply, in full 64-bit precision:
+///
+/// 1. Draw a word `x` with [`RandomSource::next_u64`].
+/// 2. Compute the 128-bit product `m = x × bound`.
+/// 3. Return the high 64 bits of `m`.
+///
+/// Every call draws exactly one word, whatever the bound. Nothing is rejected, so
+/// some results are one word more likely than others: the chance of a result is
+/// `1 / bound` off by less than `1 / 2^64`.
+///
+/// # Reference vectors
+///
+/// | `bound` | Word drawn           | Result |
+/// |---------|----------------------|--------|
+/// | `10`    | `0xffffffffffffffff` | `9`    |
+/// | `10`    | `0x8000000000000000` | `5`    |
+/// | `1300`  | `0x0123456789abcdef` | `5`    |
+/// | `355`   | `0xfedcba9876543210` | `353`  |
+/// | `3`     | `0x5555555555555556` | `1`    |
+///
+/// # Panics
+///
+/// This function will panic if `bound` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use rand::SeedableRng;
+/// use rand_chacha::ChaCha20Rng;
+/// use goofy_animals::sample_below;
+///
+/// let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
+/// assert!(sample_below(&mut rng, 10) < 10);
+/// ```
+pub fn sample_below<R: RandomSource + ?Sized>(rng: &mut R, bound: u64) -> u64 {
+    assert!(bound > 0, "cannot sample from an empty range");
+
+    ((u128::from(rng.next_u64()) * u128::from(bound)) >> 64) as u64
+}
+
+/// Draws a uniformly distributed index in `0..len`, see [`sample_below`].
+pub(crate) fn sample_index<R: RandomSource + ?Sized>(rng: &mut R, len: usize) -> usize {
+    sample_below(rng, len as u64) as usize
+}
+
+/// Draws an index in `0..len` like `Rng::random_range(0..len)` of `rand` 0.9, as
+/// used by the [`WordListVersion::V1`](crate::WordListVersion::V1) sampling method.
+///
+/// This is Canon's method: the high half of the product of a random word and `len`
+/// is the result, and when its low half is close enough to overflowing, the high
+/// half of the product of a second word decides whether to round up. Words are
+/// 32 bits wide from [`RandomSource::next_u32`] for lengths that fit in a `u32`,
+/// and 64 bits wide otherwise.
+pub(crate) fn sample_index_v1<R: RandomSource + ?Sized>(rng: &mut R, len: usize) -> usize {
+    assert!(len > 0, "cannot sample from an empty range");
+
+    if let Ok(len) = u32::try_from(len) {
+        let product = u64::from(rng.next_u32()) * u64::from(len);
+        let (result, low) = ((product >> 32) as u32, product as u32);
+
+        if low > len.wrapping_neg() {
+            let next = ((u64::from(rng.next_u32()) * u64::from(len)) >> 32) as u32;
+            return (result + u32::from(low.checked_add(next).is_none())) as usize;
+        }
+
+        return result as usize;
+    }
+
+    let len = len as u64;
+    let product = u128::from(rng.next_u64()) * u128::from(len);
+    let (result, low) = ((product >> 64) as u64, product as u64);
+
+    if low > len.wrapping_neg() {
+        let next = ((u128::from(rng.next_u64()) * u128::from(len)) >> 64) as u64;
+        return (result + u64::from(low.checked_add(next).is_none())) as usize;
+    }
+
+    result as usize
+}
+
+#[cfg(test)]
+mod test {
+    use super::{sample_below, sample_index_v1};
+    use crate::RandomSource;
+
+    use pretty_assertions::assert_eq;
+
+    /// Returns the given words, in order.
+    struct Words<'w>(&'w [u64]);
+
+    impl RandomSource for Words<'_> {
+        fn next_u64(&mut self) -> u64 {
+            let (first, rest) = self.0.split_first().expect("enough words");
+            self.0 = rest;
+            *first
+        }
+    }
+
+    #[test]
+    fn reference_vectors() {
+        for (bound, words, expected) in [
+            (10, &[0xffff_ffff_ffff_ffff][..], 9),
+            (10, &[0x8000_0000_0000_0000], 5),
+            (10, &[0x0000_0000_0000_0000], 0),
+            (1300, &[0x0123_4567_89ab_cdef], 5),
+            (355, &[0xfedc_ba98_7654_3210], 353),
+            (3, &[0x5555_5555_5555_5556], 1),
+            (3, &[0x5555_5555_5555_5555], 0),
+            (1, &[0x0123_4567_89ab_cdef], 0),
+            (u64::MAX, &[u64::MAX], u64::MAX - 1),
+        ] {
+            let mut rng = Words(words);
+            assert_eq!(
+                sample_below(&mut rng, bound),
+                expected,
+                "{bound} {words:x?}"
+            );
+            assert!(rng.0.is_empty());
+        }
+    }
+
+    #[test]
+    fn v1_matches_rand() {
+        use rand::{Rng, SeedableRng};
+        use rand_chacha::ChaCha20Rng;
+
+        let mut ours = ChaCha20Rng::seed_from_u64(0x1337);
+        let mut theirs = ours.clone();
+        for len in (1..2000).chain([u32::MAX as usize, u32::MAX as usize + 1, usize::MAX]) {
+            for _ in 0..10 {
+                assert_eq!(
+                    sample_index_v1(&mut ours, len),
+                    theirs.random_range(0..len),
+                    "{len}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn v1_reference_vectors() {
+        // 32-bit words are the low halves of these, and the low half of the first
+        // product of the last two rows is close enough to overflowing to draw again
+        for (len, words, expected) in [
+            (10, &[0xffff_ffff][..], 9),
+            (1300, &[0x89ab_cdef], 699),
+            (3, &[0xaaaa_aaaa, 0x0000_0000], 1),
+            (3, &[0xaaaa_aaaa, 0xffff_ffff], 2),
+            (1 << 32, &[0xfedc_ba98_7654_3210], 0xfedc_ba98),
+        ] {
+            let mut rng = Words(words);
+            assert_eq!(sample_index_v1(&mut rng, len), expected, "{len} {words:x?}");
+            assert!(rng.0.is_empty());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot sample from an empty range")]
+    fn empty_range() {
+        sample_below(&mut Words(&[]), 0);
+    }
+}
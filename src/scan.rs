@@ -0,0 +1,635 @@
+use core::cmp::{Ordering, Reverse};
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+use ::alloc::vec::Vec;
+
+use crate::parse::{Words, match_words};
+use crate::{GoofyAnimals, GoofyName, WordList, WordListKind, order_key};
+
+/// The number of bytes [`NameScanner::scan_reader`] reads at a time.
+#[cfg(feature = "std")]
+const READ_CHUNK: usize = 8 * 1024;
+
+/// A reusable index finding the names embedded in text, for example in logs.
+///
+/// The index holds the positions of the adjectives and animals sorted by their first
+/// word, so every word of the text is looked up with a binary search. Build it once
+/// and share it between scans.
+///
+/// A name is found where it starts and ends on a word boundary, so the characters
+/// around it mustn't be alphanumeric. Like [`GoofyAnimals::parse_name`], words are
+/// compared ignoring case and every [`NameStyle`](crate::NameStyle) is recognized,
+/// but the words of a name must follow each other directly, at a lowercase to
+/// uppercase transition, or be separated by a single character, the same one
+/// throughout the name. Names don't overlap: scanning goes on after the end of each
+/// name found.
+///
+/// Multi-word entries can make the words at a position readable as several names,
+/// such as `big red moth` with the adjectives `big`, `red` and `big red`. The longest
+/// one is found, and among those of the same length the one generated by
+/// [`GoofyAnimals::nth_name`] at the lowest position.
+///
+/// # Examples
+///
+/// ```rust
+/// use goofy_animals::{DEFAULT_GOOFY_ANIMALS, NameScanner};
+///
+/// let scanner = NameScanner::new(DEFAULT_GOOFY_ANIMALS);
+///
+/// let log = "deployed outlying-haunting-firefly, then OutlyingHauntingPolarBear";
+/// let found: Vec<_> = scanner.scan(log).map(|found| (found.start(), found.name().to_string())).collect();
+/// assert_eq!(
+///     found,
+///     [(9, "outlying-haunting-firefly".into()), (41, "outlying-haunting-polar-bear".into())]
+/// );
+///
+/// // Names glued to other words aren't found
+/// assert_eq!(scanner.scan("preoutlying-haunting-firefly").count(), 0);
+/// ```
+///
+/// # Feature Flag
+///
+/// This type is only available when the `alloc` feature is enabled.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug)]
+pub struct NameScanner<'a> {
+    animals: GoofyAnimals<'a>,
+    sorted_adjectives: Vec<usize>,
+    sorted_animals: Vec<usize>,
+    /// The most bytes a name and the character following it can span in text.
+    #[cfg(feature = "std")]
+    window: usize,
+}
+
+/// A name found in text by a [`NameScanner`].
+///
+/// # Feature Flag
+///
+/// This type is only available when the `alloc` feature is enabled.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NameMatch<'a> {
+    start: usize,
+    end: usize,
+    name: GoofyName<'a>,
+}
+
+/// The names found in a string, in order.
+///
+/// Created by [`NameScanner::scan`].
+///
+/// # Feature Flag
+///
+/// This type is only available when the `alloc` feature is enabled.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug)]
+pub struct NameMatches<'s, 'a, 't> {
+    scanner: &'s NameScanner<'a>,
+    text: &'t str,
+    position: usize,
+}
+
+/// The names found in the bytes of a reader, in order.
+///
+/// Created by [`NameScanner::scan_reader`].
+///
+/// # Feature Flag
+///
+/// This type is only available when the `std` feature is enabled.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug)]
+pub struct ReaderMatches<'s, 'a, R> {
+    scanner: &'s NameScanner<'a>,
+    reader: R,
+    buffer: Vec<u8>,
+    /// The offset in the stream of the start of the buffer.
+    offset: usize,
+    /// The position in the buffer scanning resumes at.
+    position: usize,
+    eof: bool,
+}
+
+/// The words of a name embedded in text, from a word boundary on.
+///
+/// The words are split like [`Words`], but must follow each other directly or be
+/// separated by a single character, the same one throughout the name.
+#[derive(Clone)]
+struct NameWords<'t> {
+    text: &'t str,
+    position: usize,
+    started: bool,
+    separator: Option<char>,
+}
+
+impl<'a> NameScanner<'a> {
+    /// Builds the scanning index of the word lists of `animals`.
+    ///
+    /// # Arguments
+    ///
+    /// * `animals` - The word lists of the names to find
+    ///
+    /// # Returns
+    ///
+    /// A new `NameScanner` instance.
+    pub fn new(animals: GoofyAnimals<'a>) -> Self {
+        Self {
+            animals,
+            sorted_adjectives: sorted(animals.adjective_list()),
+            sorted_animals: sorted(animals.animal_list()),
+            // Case folding can shrink a character to a quarter of its bytes, and a
+            // separator can take up to four
+            #[cfg(feature = "std")]
+            window: 4 * (animals.max_name_len() + 1),
+        }
+    }
+
+    /// Returns the [`GoofyAnimals`] instance names are found from.
+    pub fn goofy_animals(&self) -> GoofyAnimals<'a> {
+        self.animals
+    }
+
+    /// Finds the names embedded in a string.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to scan
+    ///
+    /// # Returns
+    ///
+    /// A lazy iterator over the names in `text`, with their byte offsets.
+    pub fn scan<'s, 't>(&'s self, text: &'t str) -> NameMatches<'s, 'a, 't> {
+        NameMatches {
+            scanner: self,
+            text,
+            position: 0,
+        }
+    }
+
+    /// Finds the names embedded in a stream of bytes, such as a log file.
+    ///
+    /// The stream is read in chunks, keeping only the end of the previous chunk in
+    /// memory so that names split across reads are still found. Invalid UTF-8 is
+    /// treated as a word boundary.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The stream to scan
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the names in the stream, with their byte offsets from the
+    /// start of the stream. Read errors are returned in place of a name, after which
+    /// the rest of the bytes already read are scanned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::BufReader;
+    ///
+    /// use goofy_animals::{DEFAULT_GOOFY_ANIMALS, NameScanner};
+    ///
+    /// let scanner = NameScanner::new(DEFAULT_GOOFY_ANIMALS);
+    ///
+    /// let log = BufReader::new(&b"\xff[outlying_haunting_firefly] crashed\n"[..]);
+    /// let found = scanner.scan_reader(log).next().unwrap().unwrap();
+    /// assert_eq!(found.range(), 2..27);
+    /// assert_eq!(found.name().to_string(), "outlying-haunting-firefly");
+    /// ```
+    ///
+    /// # Feature Flag
+    ///
+    /// This function is only available when the `std` feature is enabled.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn scan_reader<R: std::io::BufRead>(&self, reader: R) -> ReaderMatches<'_, 'a, R> {
+        ReaderMatches {
+            scanner: self,
+            reader,
+            buffer: Vec::new(),
+            offset: 0,
+            position: 0,
+            eof: false,
+        }
+    }
+
+    /// Finds the first name starting at or after `from` in the valid UTF-8 parts of
+    /// `bytes`.
+    #[cfg(feature = "std")]
+    fn find_in_bytes(&self, bytes: &[u8], from: usize) -> Option<NameMatch<'a>> {
+        let mut base = 0;
+        for chunk in bytes.utf8_chunks() {
+            let text = chunk.valid();
+            if from <= base + text.len()
+                && let Some(found) = self.find(text, from.saturating_sub(base))
+            {
+                return Some(found.shifted(base));
+            }
+
+            base += text.len() + chunk.invalid().len();
+        }
+
+        None
+    }
+
+    /// Finds the first name starting at or after `from` in `text`.
+    fn find(&self, text: &str, mut from: usize) -> Option<NameMatch<'a>> {
+        while !text.is_char_boundary(from) {
+            from += 1;
+        }
+
+        let mut previous = text[..from].chars().next_back();
+        for (index, c) in text[from..].char_indices() {
+            if c.is_alphanumeric()
+                && !previous.is_some_and(char::is_alphanumeric)
+                && let Some(found) = self.match_at(text, from + index)
+            {
+                return Some(found);
+            }
+
+            previous = Some(c);
+        }
+
+        None
+    }
+
+    /// Matches a name starting at the word boundary at `start`.
+    fn match_at(&self, text: &str, start: usize) -> Option<NameMatch<'a>> {
+        let mut best = None;
+        self.animals.each_reading(
+            NameWords::new(text, start),
+            &|list, words, found| {
+                let sorted = match list {
+                    WordListKind::Animals => &self.sorted_animals,
+                    WordListKind::Adjectives => &self.sorted_adjectives,
+                };
+
+                each_match(self.animals.word_list(list), sorted, words, found);
+            },
+            &mut |adjectives, animal, rest| {
+                if !rest.at_boundary() {
+                    return;
+                }
+
+                let key = (Reverse(rest.position), order_key(adjectives, animal));
+                if best.is_none_or(|best| key < best) {
+                    best = Some(key);
+                }
+            },
+        );
+
+        let (Reverse(end), (adjectives, animal)) = best?;
+        Some(NameMatch {
+            start,
+            end,
+            name: GoofyName::from_indices(
+                &self.animals,
+                &adjectives[..self.animals.adjective_count()],
+                animal,
+            ),
+        })
+    }
+}
+
+impl<'a> NameMatch<'a> {
+    /// Returns the byte offset of the start of the name.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the byte offset just past the end of the name.
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Returns the byte range of the name.
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Returns the name found, with the words of the word lists.
+    pub fn name(&self) -> GoofyName<'a> {
+        self.name
+    }
+
+    /// Moves the match `offset` bytes further.
+    #[cfg(feature = "std")]
+    fn shifted(self, offset: usize) -> Self {
+        Self {
+            start: self.start + offset,
+            end: self.end + offset,
+            ..self
+        }
+    }
+}
+
+impl<'a> Iterator for NameMatches<'_, 'a, '_> {
+    type Item = NameMatch<'a>;
+
+    fn next(&mut self) -> Option<NameMatch<'a>> {
+        let found = self.scanner.find(self.text, self.position)?;
+        self.position = found.end;
+        Some(found)
+    }
+}
+
+impl FusedIterator for NameMatches<'_, '_, '_> {}
+
+#[cfg(feature = "std")]
+impl<R: std::io::BufRead> ReaderMatches<'_, '_, R> {
+    /// Reads at least another chunk into the buffer, unless the stream ends first.
+    fn fill(&mut self) -> std::io::Result<()> {
+        let target = self.buffer.len() + READ_CHUNK;
+        while self.buffer.len() < target {
+            let available = match self.reader.fill_buf() {
+                Ok(available) => available,
+                Err(error) if error.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            };
+
+            if available.is_empty() {
+                self.eof = true;
+                break;
+            }
+
+            let length = available.len();
+            self.buffer.extend_from_slice(available);
+            self.reader.consume(length);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, R: std::io::BufRead> Iterator for ReaderMatches<'_, 'a, R> {
+    type Item = std::io::Result<NameMatch<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            // A name starting before the last window of the buffer lies entirely in
+            // it, along with the character deciding whether it ends on a boundary
+            let limit = if self.eof {
+                self.buffer.len()
+            } else {
+                self.buffer.len().saturating_sub(self.scanner.window)
+            };
+
+            if let Some(found) = self
+                .scanner
+                .find_in_bytes(&self.buffer, self.position)
+                .filter(|found| found.start < limit)
+            {
+                self.position = found.end;
+                return Some(Ok(found.shifted(self.offset)));
+            }
+
+            if self.eof {
+                return None;
+            }
+
+            // Drop the bytes scanned, but the character before the resume position
+            // that tells whether a word starts there
+            self.position = self.position.max(limit);
+            let mut keep = self.position.saturating_sub(1);
+            while keep > 0 && self.buffer[keep] & 0xc0 == 0x80 {
+                keep -= 1;
+            }
+
+            self.buffer.drain(..keep);
+            self.offset += keep;
+            self.position -= keep;
+
+            if let Err(error) = self.fill() {
+                self.eof = true;
+                return Some(Err(error));
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::BufRead> FusedIterator for ReaderMatches<'_, '_, R> {}
+
+impl<'t> NameWords<'t> {
+    fn new(text: &'t str, start: usize) -> Self {
+        Self {
+            text,
+            position: start,
+            started: false,
+            separator: None,
+        }
+    }
+
+    /// Checks whether the words read so far end on a word boundary.
+    fn at_boundary(&self) -> bool {
+        !self.text[self.position..]
+            .chars()
+            .next()
+            .is_some_and(char::is_alphanumeric)
+    }
+}
+
+impl<'t> Iterator for NameWords<'t> {
+    type Item = &'t str;
+
+    fn next(&mut self) -> Option<&'t str> {
+        let mut rest = &self.text[self.position..];
+        let first = rest.chars().next()?;
+
+        if self.started && !first.is_alphanumeric() {
+            if *self.separator.get_or_insert(first) != first {
+                return None;
+            }
+
+            self.position += first.len_utf8();
+            rest = &rest[first.len_utf8()..];
+        }
+
+        // A word must start right here, rather than after more separators
+        if !rest.starts_with(char::is_alphanumeric) {
+            return None;
+        }
+
+        let word = Words::new(rest).next()?;
+        self.position += word.len();
+        self.started = true;
+        Some(word)
+    }
+}
+
+/// Returns the positions of the entries of a list, sorted by their first word.
+fn sorted(list: WordList<'_>) -> Vec<usize> {
+    let mut sorted: Vec<usize> = (0..list.len()).collect();
+    sorted.sort_by(|&a, &b| first_word(list.word(a)).cmp(first_word(list.word(b))));
+    sorted
+}
+
+/// Returns the first word of a word list entry.
+fn first_word(entry: &str) -> &str {
+    Words::new(entry).next().unwrap_or_default()
+}
+
+/// Compares the first word of a word list entry to a word of the text, ignoring the
+/// case of the latter.
+fn first_word_cmp(entry: &str, word: &str) -> Ordering {
+    first_word(entry)
+        .chars()
+        .cmp(word.chars().flat_map(char::to_lowercase))
+}
+
+/// Calls `found` with the position of every entry of `list` matching the next
+/// words, and the words following it. `sorted` holds the positions of the entries by
+/// first word.
+fn each_match<'t>(
+    list: WordList<'_>,
+    sorted: &[usize],
+    words: NameWords<'t>,
+    found: &mut dyn FnMut(usize, NameWords<'t>),
+) {
+    let Some(word) = words.clone().next() else {
+        return;
+    };
+
+    let start = sorted.partition_point(|&index| first_word_cmp(list.word(index), word).is_lt());
+    for &index in sorted[start..]
+        .iter()
+        .take_while(|&&index| first_word_cmp(list.word(index), word).is_eq())
+    {
+        if let Some(rest) = match_words(list.word(index), words.clone()) {
+            found(index, rest);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::NameScanner;
+    use crate::DEFAULT_GOOFY_ANIMALS;
+    use crate::fixtures::{ANIMALS, STYLES};
+
+    use pretty_assertions::assert_eq;
+
+    fn scan(text: &str) -> ::alloc::vec::Vec<(usize, usize, ::alloc::string::String)> {
+        use ::alloc::string::ToString;
+
+        NameScanner::new(ANIMALS)
+            .scan(text)
+            .map(|found| (found.start(), found.end(), found.name().to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn boundaries() {
+        assert_eq!(scan("red-big-moth"), [(0, 12, "red-big-moth".into())]);
+        assert_eq!(
+            scan("(red-big-moth),Shy_Red_Polar_Bear."),
+            [
+                (1, 13, "red-big-moth".into()),
+                (15, 33, "shy-red-polar-bear".into())
+            ]
+        );
+        assert_eq!(
+            scan("big red shy polar bear"),
+            [(0, 22, "big-red-shy-polar-bear".into())]
+        );
+        assert_eq!(scan("redBigMoth"), [(0, 10, "red-big-moth".into())]);
+
+        // Every split of the words is tried, the longest name wins
+        assert_eq!(scan("big-red-moth"), [(0, 12, "big-red-moth".into())]);
+        let found = NameScanner::new(ANIMALS).scan("big-red-moth").next();
+        assert_eq!(found.unwrap().name().adjectives(), ["big", "red"]);
+        assert_eq!(
+            found.map(|found| found.name()),
+            ANIMALS.parse_name("big-red-moth").ok()
+        );
+        assert_eq!(
+            scan("big red polar bear!"),
+            [(0, 18, "big-red-polar-bear".into())]
+        );
+        assert_eq!(
+            scan("big-red-shy-moth"),
+            [(0, 16, "big-red-shy-moth".into())]
+        );
+
+        // Names end at the first boundary after the longest entry
+        assert_eq!(
+            scan("red-big-polar bear"),
+            [(0, 13, "red-big-polar".into())]
+        );
+        assert_eq!(scan("red-big-moth-2"), [(0, 12, "red-big-moth".into())]);
+
+        // Names must be delimited by non-alphanumeric characters
+        assert_eq!(scan("xred-big-moth"), []);
+        assert_eq!(scan("éred-big-moth"), []);
+        assert_eq!(scan("red-big-moths"), []);
+        assert_eq!(scan("redBigMothX"), []);
+
+        // Separators are single and consistent, and adjectives don't repeat
+        assert_eq!(scan("red--big-moth"), []);
+        assert_eq!(scan("red_big-moth"), []);
+        assert_eq!(scan("red-red-moth"), []);
+        assert_eq!(
+            scan("shy-red-red-big-moth"),
+            [(8, 20, "red-big-moth".into())]
+        );
+        assert_eq!(scan(""), []);
+    }
+
+    #[test]
+    fn styles() {
+        use ::alloc::format;
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha20Rng;
+
+        let scanner = NameScanner::new(DEFAULT_GOOFY_ANIMALS);
+        let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
+        for _ in 0..100 {
+            let name = DEFAULT_GOOFY_ANIMALS.generate_name_parts(&mut rng);
+
+            for style in STYLES {
+                let styled = format!("{}", name.styled(style));
+                let text = format!("level=info name=\"{styled}\" ok");
+
+                let found: ::alloc::vec::Vec<_> = scanner.scan(&text).collect();
+                assert_eq!(found.len(), 1, "{text}");
+                assert_eq!(found[0].name(), name);
+                assert_eq!(&text[found[0].range()], styled);
+            }
+        }
+    }
+
+    #[test]
+    fn reader() {
+        use ::alloc::vec::Vec;
+        use std::io::BufReader;
+
+        let scanner = NameScanner::new(ANIMALS);
+
+        // Names at every position relative to the chunks read
+        let mut text = ::alloc::string::String::new();
+        for filler in 0..3000 {
+            text.push_str(&"é ".repeat(filler % 7));
+            text.push_str(["red-big-moth ", "Shy Red Polar Bear\n", "bigRedShyBear,"][filler % 3]);
+        }
+
+        let expected: Vec<_> = scanner.scan(&text).collect();
+        assert_eq!(expected.len(), 3000);
+        for capacity in [1, 7, 4096] {
+            let found: Vec<_> = scanner
+                .scan_reader(BufReader::with_capacity(capacity, text.as_bytes()))
+                .collect::<Result<_, _>>()
+                .unwrap();
+            assert_eq!(found, expected);
+        }
+
+        // Invalid UTF-8 separates words
+        let bytes = b"\xffred-big-moth\xc3 shy-red-moth\xffbig";
+        let found: Vec<_> = scanner
+            .scan_reader(&bytes[..])
+            .map(|found| found.unwrap().range())
+            .collect();
+        assert_eq!(found, [1..13, 15..27]);
+    }
+}
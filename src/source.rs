@@ -0,0 +1,232 @@
+/// A source of uniformly distributed random 64-bit words.
+///
+/// This is the only thing the name generation functions need from a random number
+/// generator: every word is picked from raw words, in the way set by
+/// [`GoofyAnimals::sampling`](crate::GoofyAnimals::sampling). Implement it to plug in
+/// any generator, or use one of the provided adapters:
+///
+/// | Generator               | Adapter                         | Feature    |
+/// |-------------------------|---------------------------------|------------|
+/// | `rand` 0.9 `RngCore`    | none, implemented for every RNG | `rand`     |
+/// | `rand` 0.8 `RngCore`    | `Rand08`                        | `rand08`   |
+/// | `fastrand::Rng`         | `FastRand`                      | `fastrand` |
+/// | `FnMut() -> u64`        | [`RandomFn`]                    |            |
+///
+/// # Examples
+///
+/// ```rust
+/// use goofy_animals::{DEFAULT_GOOFY_ANIMALS, RandomSource};
+///
+/// /// A hardware generator returning 64 random bits per read.
+/// struct Trng(u64);
+///
+/// impl RandomSource for Trng {
+///     fn next_u64(&mut self) -> u64 {
+///         self.0 = self.0.wrapping_mul(0x5851_f42d_4c95_7f2d).wrapping_add(1);
+///         self.0
+///     }
+/// }
+///
+/// assert_eq!(DEFAULT_GOOFY_ANIMALS.generate_name(&mut Trng(0)), "abandoned-fond-felidae");
+/// ```
+pub trait RandomSource {
+    /// Returns the next random word, with all 64 bits uniformly distributed.
+    fn next_u64(&mut self) -> u64;
+
+    /// Returns the next random 32-bit word, with all bits uniformly distributed.
+    ///
+    /// Only the [`WordListVersion::V1`](crate::WordListVersion::V1) sampling method
+    /// draws 32-bit words. The default implementation returns the low half of
+    /// [`RandomSource::next_u64`]; generators producing 32-bit words natively should
+    /// return them directly, so that names match those of `rand` 0.9's
+    /// `random_range`.
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+}
+
+/// Every `rand` 0.9 generator is a random source.
+///
+/// # Feature Flag
+///
+/// This implementation is only available when the `rand` feature is enabled.
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl<R: rand::RngCore + ?Sized> RandomSource for R {
+    fn next_u64(&mut self) -> u64 {
+        rand::RngCore::next_u64(self)
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        rand::RngCore::next_u32(self)
+    }
+}
+
+/// Adapts a `rand` 0.8 generator, or any other `rand_core` 0.6 `RngCore`, to
+/// [`RandomSource`].
+///
+/// The generator can be owned or borrowed, as `rand_core` implements `RngCore` for
+/// `&mut R`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use goofy_animals::{Rand08, generate_name};
+///
+/// let mut rng = rand::thread_rng();
+/// let name = generate_name(&mut Rand08(&mut rng));
+/// ```
+///
+/// # Feature Flag
+///
+/// This type is only available when the `rand08` feature is enabled.
+#[cfg(feature = "rand08")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand08")))]
+#[derive(Clone, Debug)]
+pub struct Rand08<R>(pub R);
+
+#[cfg(feature = "rand08")]
+impl<R: rand08::RngCore> RandomSource for Rand08<R> {
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+}
+
+/// Adapts a [`fastrand::Rng`] to [`RandomSource`].
+///
+/// The generator can be owned or borrowed.
+///
+/// # Examples
+///
+/// ```rust
+/// use goofy_animals::{DEFAULT_GOOFY_ANIMALS, FastRand};
+///
+/// let mut rng = fastrand::Rng::with_seed(0x1337);
+/// let name = DEFAULT_GOOFY_ANIMALS.generate_name(&mut FastRand(&mut rng));
+///
+/// let mut owned = FastRand(fastrand::Rng::with_seed(0x1337));
+/// assert_eq!(DEFAULT_GOOFY_ANIMALS.generate_name(&mut owned), name);
+/// ```
+///
+/// # Feature Flag
+///
+/// This type is only available when the `fastrand` feature is enabled.
+#[cfg(feature = "fastrand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fastrand")))]
+#[derive(Clone, Debug)]
+pub struct FastRand<R = fastrand::Rng>(pub R);
+
+#[cfg(feature = "fastrand")]
+impl<R: core::borrow::BorrowMut<fastrand::Rng>> RandomSource for FastRand<R> {
+    fn next_u64(&mut self) -> u64 {
+        self.0.borrow_mut().u64(..)
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        self.0.borrow_mut().u32(..)
+    }
+}
+
+/// Adapts a closure returning random 64-bit words to [`RandomSource`].
+///
+/// # Examples
+///
+/// ```rust
+/// use goofy_animals::{DEFAULT_GOOFY_ANIMALS, RandomFn};
+///
+/// // A xorshift generator, standing in for a hardware source
+/// let mut state = 0x1337_u64;
+/// let mut rng = RandomFn(|| {
+///     state ^= state << 13;
+///     state ^= state >> 7;
+///     state ^= state << 17;
+///     state
+/// });
+///
+/// let name = DEFAULT_GOOFY_ANIMALS.generate_name_parts(&mut rng);
+/// assert_eq!(name.adjectives().len(), 2);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct RandomFn<F>(pub F);
+
+impl<F: FnMut() -> u64> RandomSource for RandomFn<F> {
+    fn next_u64(&mut self) -> u64 {
+        (self.0)()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{RandomFn, RandomSource};
+    use crate::{DEFAULT_GOOFY_ANIMALS, WordListVersion};
+
+    use pretty_assertions::assert_eq;
+
+    /// Draws a few words, to compare sources.
+    fn words(source: &mut impl RandomSource) -> [u64; 4] {
+        core::array::from_fn(|_| source.next_u64())
+    }
+
+    #[test]
+    fn adapters() {
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha20Rng;
+
+        let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
+        let expected: [u64; 4] = core::array::from_fn(|_| rand::RngCore::next_u64(&mut rng));
+        assert_eq!(words(&mut ChaCha20Rng::seed_from_u64(0x1337)), expected);
+
+        let mut rng = fastrand::Rng::with_seed(0x1337);
+        let expected = words(&mut RandomFn(|| rng.u64(..)));
+        assert_eq!(
+            words(&mut super::FastRand(fastrand::Rng::with_seed(0x1337))),
+            expected
+        );
+
+        let mut counter = 0;
+        let mut counting = RandomFn(|| {
+            counter += 1;
+            u64::MAX
+        });
+        DEFAULT_GOOFY_ANIMALS
+            .with_sampling(WordListVersion::V2)
+            .generate_name_parts(&mut counting);
+        assert_eq!(counter, 3);
+    }
+
+    #[test]
+    fn rand08() {
+        use rand08::RngCore;
+
+        /// A `rand_core` 0.6 generator counting up.
+        struct Counter(u64);
+
+        impl RngCore for Counter {
+            fn next_u32(&mut self) -> u32 {
+                self.next_u64() as u32
+            }
+
+            fn next_u64(&mut self) -> u64 {
+                self.0 += 1;
+                self.0
+            }
+
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                rand08::impls::fill_bytes_via_next(self, dest)
+            }
+
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand08::Error> {
+                self.fill_bytes(dest);
+                Ok(())
+            }
+        }
+
+        let mut rng = Counter(0);
+        assert_eq!(words(&mut super::Rand08(&mut rng)), [1, 2, 3, 4]);
+        assert_eq!(words(&mut super::Rand08(rng)), [5, 6, 7, 8]);
+    }
+}
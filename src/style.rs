@@ -0,0 +1,156 @@
+use core::fmt::{Display, Formatter, Write};
+
+use crate::GoofyName;
+
+/// The casing and separator used when turning a [`GoofyName`] into text.
+///
+/// Word list entries made of several words, such as `polar bear`, are split into
+/// their words first, so every style treats them like any other word of the name.
+///
+/// | Style                        | Example                        |
+/// |------------------------------|--------------------------------|
+/// | [`NameStyle::Kebab`]         | `dismal-outrageous-polar-bear` |
+/// | [`NameStyle::Snake`]         | `dismal_outrageous_polar_bear` |
+/// | [`NameStyle::Camel`]         | `dismalOutrageousPolarBear`    |
+/// | [`NameStyle::Pascal`]        | `DismalOutrageousPolarBear`    |
+/// | [`NameStyle::ScreamingSnake`]| `DISMAL_OUTRAGEOUS_POLAR_BEAR` |
+/// | [`NameStyle::Title`]         | `Dismal Outrageous Polar Bear` |
+/// | [`NameStyle::Custom`]`('.')` | `dismal.outrageous.polar.bear` |
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NameStyle {
+    /// Lowercase words joined with `-`. This is how names are displayed by default.
+    #[default]
+    Kebab,
+    /// Lowercase words joined with `_`.
+    Snake,
+    /// Words joined without a separator, capitalized except for the first one.
+    Camel,
+    /// Capitalized words joined without a separator.
+    Pascal,
+    /// Uppercase words joined with `_`.
+    ScreamingSnake,
+    /// Capitalized words joined with spaces.
+    Title,
+    /// Lowercase words joined with the given separator.
+    Custom(char),
+}
+
+impl NameStyle {
+    /// Returns the character placed between words, if any.
+    pub const fn separator(self) -> Option<char> {
+        match self {
+            Self::Kebab => Some('-'),
+            Self::Snake | Self::ScreamingSnake => Some('_'),
+            Self::Camel | Self::Pascal => None,
+            Self::Title => Some(' '),
+            Self::Custom(separator) => Some(separator),
+        }
+    }
+
+    fn write_word(self, f: &mut Formatter<'_>, word: &str, first: bool) -> core::fmt::Result {
+        let capitalize = match self {
+            Self::Kebab | Self::Snake | Self::Custom(_) => false,
+            Self::Camel => !first,
+            Self::Pascal | Self::Title => true,
+            Self::ScreamingSnake => {
+                return word
+                    .chars()
+                    .flat_map(char::to_uppercase)
+                    .try_for_each(|c| f.write_char(c));
+            }
+        };
+
+        let mut chars = word.chars();
+        if capitalize && let Some(c) = chars.next() {
+            c.to_uppercase().try_for_each(|c| f.write_char(c))?;
+        }
+
+        chars
+            .flat_map(char::to_lowercase)
+            .try_for_each(|c| f.write_char(c))
+    }
+}
+
+/// A [`GoofyName`] displayed in a given [`NameStyle`].
+///
+/// Created by [`GoofyName::styled`].
+#[derive(Clone, Copy, Debug)]
+pub struct StyledName<'n, 'a> {
+    name: &'n GoofyName<'a>,
+    style: NameStyle,
+}
+
+impl<'n, 'a> StyledName<'n, 'a> {
+    pub(crate) const fn new(name: &'n GoofyName<'a>, style: NameStyle) -> Self {
+        Self { name, style }
+    }
+}
+
+impl Display for StyledName<'_, '_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let words = self
+            .name
+            .adjectives()
+            .iter()
+            .copied()
+            .chain([self.name.animal()])
+            .flat_map(str::split_whitespace);
+
+        for (position, word) in words.enumerate() {
+            if position > 0
+                && let Some(separator) = self.style.separator()
+            {
+                f.write_char(separator)?;
+            }
+
+            self.style.write_word(f, word, position == 0)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::NameStyle;
+    use crate::{GoofyAnimals, GoofyName};
+
+    use pretty_assertions::assert_eq;
+
+    const ANIMALS: GoofyAnimals<'static> =
+        GoofyAnimals::new(&["moth", "polar bear"], &["dismal", "outrageous"]);
+
+    #[test]
+    fn styles() {
+        let name = GoofyName::from_indices(&ANIMALS, &[0, 1], 1);
+
+        for (style, expected) in [
+            (NameStyle::Kebab, "dismal-outrageous-polar-bear"),
+            (NameStyle::Snake, "dismal_outrageous_polar_bear"),
+            (NameStyle::Camel, "dismalOutrageousPolarBear"),
+            (NameStyle::Pascal, "DismalOutrageousPolarBear"),
+            (NameStyle::ScreamingSnake, "DISMAL_OUTRAGEOUS_POLAR_BEAR"),
+            (NameStyle::Title, "Dismal Outrageous Polar Bear"),
+            (NameStyle::Custom('.'), "dismal.outrageous.polar.bear"),
+        ] {
+            assert_eq!(::alloc::format!("{}", name.styled(style)), expected);
+        }
+
+        assert_eq!(::alloc::format!("{name}"), "dismal-outrageous-polar-bear");
+    }
+
+    #[test]
+    fn single_word() {
+        let animals = ANIMALS.with_adjective_count(0);
+        let name = GoofyName::from_indices(&animals, &[], 0);
+
+        assert_eq!(
+            ::alloc::format!("{}", name.styled(NameStyle::Camel)),
+            "moth"
+        );
+        assert_eq!(
+            ::alloc::format!("{}", name.styled(NameStyle::Pascal)),
+            "Moth"
+        );
+    }
+}
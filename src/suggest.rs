@@ -0,0 +1,371 @@
+use ::alloc::vec::Vec;
+
+use crate::parse::Words;
+use crate::{GoofyAnimals, GoofyName, MAX_ADJECTIVES, WordList, order_key};
+
+/// The largest edit distance per word accepted by [`GoofyAnimals::suggest`].
+///
+/// # Feature Flag
+///
+/// This constant is only available when the `alloc` feature is enabled.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub const MAX_SUGGESTION_DISTANCE: usize = 3;
+
+/// A valid name close to a mistyped one, returned by [`GoofyAnimals::suggest`].
+///
+/// # Feature Flag
+///
+/// This type is only available when the `alloc` feature is enabled.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Suggestion<'a> {
+    name: GoofyName<'a>,
+    distance: usize,
+}
+
+impl<'a> Suggestion<'a> {
+    /// Returns the suggested name.
+    pub fn name(&self) -> GoofyName<'a> {
+        self.name
+    }
+
+    /// Returns the sum of the edit distances between the words of the suggested name
+    /// and those of the mistyped one.
+    pub fn distance(&self) -> usize {
+        self.distance
+    }
+}
+
+/// The list entries within the distance threshold of each span of words of a name,
+/// as pairs of entry positions and edit distances.
+struct Candidates {
+    /// Adjective candidates, by first word and number of words.
+    adjectives: Vec<Vec<Vec<(usize, usize)>>>,
+    /// Animal candidates made of every word from a given one on.
+    animals: Vec<Vec<(usize, usize)>>,
+}
+
+impl<'a> GoofyAnimals<'a> {
+    /// Suggests the valid names closest to a possibly mistyped one.
+    ///
+    /// The name is split into words like in [`GoofyAnimals::parse_name`], so case and
+    /// separators don't matter. Each adjective and the animal is then compared to
+    /// the word lists by Levenshtein distance: the number of characters to insert,
+    /// delete or substitute to go from one to the other. Spans of several words are
+    /// compared to multi-word entries with the words joined by spaces, so
+    /// `polarbear` is one edit away from `polar bear`.
+    ///
+    /// A name is suggested if every one of its words is at most `max_distance` edits
+    /// away from the matching part of `name`, with `max_distance` capped at
+    /// [`MAX_SUGGESTION_DISTANCE`]. Suggestions are ranked by the total number of
+    /// edits, then by their position in the name space, and a valid name is its own
+    /// first suggestion with a distance of zero.
+    ///
+    /// Only the first `limit` suggestions are returned. Candidates for each word are
+    /// pruned to the closest few before they're combined, so the work stays bounded
+    /// by `limit` rather than by the number of names within `max_distance`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The possibly mistyped name
+    /// * `max_distance` - The largest edit distance allowed for each word
+    /// * `limit` - The largest number of suggestions to return
+    ///
+    /// # Returns
+    ///
+    /// Up to `limit` suggested names, closest first. It's empty if no name is close
+    /// enough.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use goofy_animals::DEFAULT_GOOFY_ANIMALS;
+    ///
+    /// let suggestions = DEFAULT_GOOFY_ANIMALS.suggest("dismall-outlyng-moth", 2, 5);
+    /// assert_eq!(suggestions.len(), 5);
+    /// assert_eq!(suggestions[0].name().to_string(), "dismal-outlying-moth");
+    /// assert_eq!(suggestions[0].distance(), 2);
+    ///
+    /// assert!(DEFAULT_GOOFY_ANIMALS.suggest("dismall-outlyng-moth", 0, 5).is_empty());
+    /// ```
+    ///
+    /// # Feature Flag
+    ///
+    /// This function is only available when the `alloc` feature is enabled.
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn suggest(&self, name: &str, max_distance: usize, limit: usize) -> Vec<Suggestion<'a>> {
+        if limit == 0 {
+            return Vec::new();
+        }
+
+        let max_distance = max_distance.min(MAX_SUGGESTION_DISTANCE);
+        // A candidate dropped from a slot loses to at least `limit` others combined with
+        // the same words, even after removing those repeating the other adjectives
+        let keep = limit.saturating_add(MAX_ADJECTIVES);
+
+        let words: Vec<Vec<char>> = Words::new(name)
+            .map(|word| word.chars().flat_map(char::to_lowercase).collect())
+            .collect();
+
+        let adjectives = normalize(self.adjectives);
+        let animals = normalize(self.animals);
+        let span_words = adjectives
+            .iter()
+            .map(|(_, count)| *count)
+            .max()
+            .unwrap_or(1);
+
+        let candidates = Candidates {
+            adjectives: (0..words.len())
+                .map(|start| {
+                    (1..=span_words.min(words.len() - start))
+                        .map(|count| {
+                            within(
+                                &adjectives,
+                                &join(&words[start..start + count]),
+                                max_distance,
+                                keep,
+                            )
+                        })
+                        .collect()
+                })
+                .collect(),
+            animals: (0..words.len())
+                .map(|start| within(&animals, &join(&words[start..]), max_distance, keep))
+                .collect(),
+        };
+
+        let mut found = Vec::new();
+        self.collect_suggestions(&candidates, 0, 0, &mut [0; MAX_ADJECTIVES], 0, &mut found);
+
+        // Different splits of the words can lead to the same name, keep the closest
+        found.sort_unstable_by_key(|&(rank, suggestion)| (rank, suggestion.distance));
+        found.dedup_by_key(|(rank, _)| *rank);
+        found.sort_unstable_by_key(|&(rank, suggestion)| (suggestion.distance, rank));
+
+        found
+            .into_iter()
+            .take(limit)
+            .map(|(_, suggestion)| suggestion)
+            .collect()
+    }
+
+    /// Adds every combination of candidates for the remaining adjectives and the
+    /// animal, starting at the word `start`, to `found` along with its rank in the
+    /// name space.
+    fn collect_suggestions(
+        &self,
+        candidates: &Candidates,
+        slot: usize,
+        start: usize,
+        chosen: &mut [usize; MAX_ADJECTIVES],
+        distance: usize,
+        found: &mut Vec<(([usize; MAX_ADJECTIVES], usize), Suggestion<'a>)>,
+    ) {
+        if slot == self.adjective_count {
+            for &(animal, animal_distance) in candidates.animals.get(start).into_iter().flatten() {
+                let adjectives = &chosen[..self.adjective_count];
+                found.push((
+                    order_key(adjectives, animal),
+                    Suggestion {
+                        name: GoofyName::from_indices(self, adjectives, animal),
+                        distance: distance + animal_distance,
+                    },
+                ));
+            }
+
+            return;
+        }
+
+        let Some(spans) = candidates.adjectives.get(start) else {
+            return;
+        };
+
+        for (count, span) in (1..).zip(spans) {
+            for &(adjective, adjective_distance) in span {
+                if chosen[..slot].contains(&adjective) {
+                    continue;
+                }
+
+                chosen[slot] = adjective;
+                self.collect_suggestions(
+                    candidates,
+                    slot + 1,
+                    start + count,
+                    chosen,
+                    distance + adjective_distance,
+                    found,
+                );
+            }
+        }
+    }
+}
+
+/// Returns the words of every entry of a list joined by spaces, along with their
+/// number.
+fn normalize(list: WordList<'_>) -> Vec<(Vec<char>, usize)> {
+    list.iter()
+        .map(|entry| {
+            let words: Vec<Vec<char>> = Words::new(entry)
+                .map(|word| word.chars().collect())
+                .collect();
+            (join(&words), words.len())
+        })
+        .collect()
+}
+
+/// Joins words with spaces.
+fn join(words: &[Vec<char>]) -> Vec<char> {
+    words.join(&' ')
+}
+
+/// Returns the position and distance of the `keep` closest entries at most
+/// `max_distance` edits away from `text`, closest and then first in the list first.
+fn within(
+    entries: &[(Vec<char>, usize)],
+    text: &[char],
+    max_distance: usize,
+    keep: usize,
+) -> Vec<(usize, usize)> {
+    let mut within: Vec<(usize, usize)> = entries
+        .iter()
+        .enumerate()
+        .filter(|(_, (entry, _))| entry.len().abs_diff(text.len()) <= max_distance)
+        .map(|(index, (entry, _))| (index, edit_distance(entry, text)))
+        .filter(|&(_, distance)| distance <= max_distance)
+        .collect();
+
+    within.sort_unstable_by_key(|&(index, distance)| (distance, index));
+    within.truncate(keep);
+    within
+}
+
+/// Computes the Levenshtein distance between two strings.
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = ::alloc::vec![0; b.len() + 1];
+
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+
+        core::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod test {
+    use super::{MAX_SUGGESTION_DISTANCE, edit_distance};
+    use crate::{DEFAULT_GOOFY_ANIMALS, GoofyAnimals};
+
+    use pretty_assertions::assert_eq;
+
+    const ANIMALS: GoofyAnimals<'static> = GoofyAnimals::new(
+        &["moth", "polar bear", "bear", "mole"],
+        &["big", "big red", "red", "shy", "sly"],
+    );
+
+    fn suggest(
+        name: &str,
+        max_distance: usize,
+    ) -> ::alloc::vec::Vec<(::alloc::string::String, usize)> {
+        use ::alloc::string::ToString;
+
+        ANIMALS
+            .suggest(name, max_distance, usize::MAX)
+            .iter()
+            .map(|suggestion| (suggestion.name().to_string(), suggestion.distance()))
+            .collect()
+    }
+
+    #[test]
+    fn distances() {
+        let distance = |a: &str, b: &str| {
+            let a: ::alloc::vec::Vec<char> = a.chars().collect();
+            let b: ::alloc::vec::Vec<char> = b.chars().collect();
+            edit_distance(&a, &b)
+        };
+
+        assert_eq!(distance("", ""), 0);
+        assert_eq!(distance("moth", ""), 4);
+        assert_eq!(distance("moth", "moth"), 0);
+        assert_eq!(distance("moth", "mouth"), 1);
+        assert_eq!(distance("moth", "math"), 1);
+        assert_eq!(distance("kitten", "sitting"), 3);
+        assert_eq!(distance("polarbear", "polar bear"), 1);
+    }
+
+    #[test]
+    fn suggestions() {
+        // A valid name comes first
+        assert_eq!(
+            suggest("Shy-Big-Moth", 1),
+            [("shy-big-moth".into(), 0), ("sly-big-moth".into(), 1),]
+        );
+
+        assert_eq!(suggest("shy-big-moth", 0), [("shy-big-moth".into(), 0)]);
+        assert_eq!(
+            suggest("shy-big-moth", 2),
+            [
+                ("shy-big-moth".into(), 0),
+                ("sly-big-moth".into(), 1),
+                ("shy-big-mole".into(), 2),
+                ("sly-big-mole".into(), 3),
+            ]
+        );
+        assert_eq!(
+            suggest("bigred_sly_polarbear", 1),
+            [
+                ("big-red-sly-polar-bear".into(), 2),
+                ("big-red-shy-polar-bear".into(), 3),
+            ]
+        );
+        assert_eq!(suggest("big-red-moth", 0), [("big-red-moth".into(), 0)]);
+
+        assert_eq!(suggest("", 2), []);
+        assert_eq!(suggest("big-moth", 2), []);
+        assert_eq!(suggest("tall-small-unicorn", 2), []);
+    }
+
+    #[test]
+    fn default_lists() {
+        let suggestions = DEFAULT_GOOFY_ANIMALS.suggest("outlyng-hauntin-polarbear", 2, 50);
+        assert_eq!(
+            suggestions[0].name(),
+            DEFAULT_GOOFY_ANIMALS
+                .parse_name("outlying-haunting-polar-bear")
+                .unwrap()
+        );
+        assert_eq!(suggestions[0].distance(), 3);
+        assert!(
+            suggestions
+                .windows(2)
+                .all(|pair| pair[0].distance() <= pair[1].distance())
+        );
+    }
+
+    #[test]
+    fn limits() {
+        // Pruning the candidates of each word keeps the closest suggestions
+        let all = DEFAULT_GOOFY_ANIMALS.suggest("dismall-outlyng-moth", 3, usize::MAX);
+        assert_eq!(all.len(), 408);
+        for limit in [1, 2, 10, 100] {
+            assert_eq!(
+                DEFAULT_GOOFY_ANIMALS.suggest("dismall-outlyng-moth", 3, limit),
+                all[..limit]
+            );
+        }
+
+        // Larger distances are capped
+        assert_eq!(
+            ANIMALS.suggest("shy-big-moth", usize::MAX, usize::MAX),
+            ANIMALS.suggest("shy-big-moth", MAX_SUGGESTION_DISTANCE, usize::MAX)
+        );
+        assert_eq!(ANIMALS.suggest("shy-big-moth", 2, 0), []);
+    }
+}
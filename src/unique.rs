@@ -0,0 +1,225 @@
+use core::iter::FusedIterator;
+
+use crate::{GoofyAnimals, GoofyName};
+
+/// Number of Feistel rounds used to shuffle the name space.
+const ROUNDS: u64 = 4;
+
+/// A generator that never repeats a name until the whole name space is exhausted.
+///
+/// `UniqueNameGenerator` walks a seeded pseudo-random permutation of every name a
+/// [`GoofyAnimals`] instance can produce (see [`GoofyAnimals::nth_name`]). The
+/// permutation is computed on the fly with a small Feistel network, so the only
+/// state is the seed and the current position. Both can be stored and later passed
+/// to [`UniqueNameGenerator::resume`] to continue where the generator left off.
+///
+/// Once every name has been handed out, the generator returns `None`.
+///
+/// # Examples
+///
+/// ```rust
+/// use goofy_animals::{DEFAULT_GOOFY_ANIMALS, UniqueNameGenerator};
+///
+/// let mut names = UniqueNameGenerator::new(DEFAULT_GOOFY_ANIMALS, 0x1337);
+/// let first = names.next().unwrap();
+/// let second = names.next().unwrap();
+/// assert_ne!(first, second);
+///
+/// // Continue from a checkpoint
+/// let mut resumed = UniqueNameGenerator::resume(DEFAULT_GOOFY_ANIMALS, names.seed(), 1);
+/// assert_eq!(resumed.next(), Some(second));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct UniqueNameGenerator<'a> {
+    animals: GoofyAnimals<'a>,
+    seed: u64,
+    position: u64,
+}
+
+impl<'a> UniqueNameGenerator<'a> {
+    /// Creates a new generator walking the name space of `animals` in an order
+    /// determined by `seed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `animals` - The word lists to generate names from
+    /// * `seed` - The seed selecting the permutation of the name space
+    ///
+    /// # Returns
+    ///
+    /// A new `UniqueNameGenerator` positioned at the start of the permutation.
+    pub const fn new(animals: GoofyAnimals<'a>, seed: u64) -> Self {
+        Self::resume(animals, seed, 0)
+    }
+
+    /// Recreates a generator from a previously saved seed and position.
+    ///
+    /// # Arguments
+    ///
+    /// * `animals` - The word lists to generate names from
+    /// * `seed` - The seed selecting the permutation of the name space
+    /// * `position` - The number of names already handed out
+    ///
+    /// # Returns
+    ///
+    /// A `UniqueNameGenerator` that continues the permutation at `position`.
+    pub const fn resume(animals: GoofyAnimals<'a>, seed: u64, position: u64) -> Self {
+        Self {
+            animals,
+            seed,
+            position,
+        }
+    }
+
+    /// Returns the seed selecting the permutation of the name space.
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the number of names handed out so far.
+    pub const fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the number of names left before the generator is exhausted.
+    pub const fn remaining(&self) -> u64 {
+        self.animals.combinations().saturating_sub(self.position)
+    }
+
+    /// Returns `true` once every name has been handed out.
+    pub const fn is_exhausted(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Maps a position to its index in the name space.
+    ///
+    /// The Feistel network permutes a power-of-four domain that is at most four
+    /// times larger than the name space; values falling outside the name space are
+    /// fed through the network again ("cycle walking") until they land inside it,
+    /// which keeps the mapping a permutation.
+    fn permute(&self, position: u64, total: u64) -> u64 {
+        let bits = u64::BITS - (total - 1).leading_zeros();
+        let half_bits = bits.div_ceil(2).max(1);
+        let mask = (1u64 << half_bits) - 1;
+
+        let mut value = position;
+        loop {
+            let mut left = value >> half_bits;
+            let mut right = value & mask;
+
+            for round in 0..ROUNDS {
+                let key = mix(self.seed ^ round.wrapping_mul(0x9e37_79b9_7f4a_7c15));
+                let next = left ^ (mix(right ^ key) & mask);
+                left = right;
+                right = next;
+            }
+
+            value = (left << half_bits) | right;
+            if value < total {
+                return value;
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for UniqueNameGenerator<'a> {
+    type Item = GoofyName<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let total = self.animals.combinations();
+        if self.position >= total {
+            return None;
+        }
+
+        let index = self.permute(self.position, total);
+        self.position += 1;
+
+        self.animals.nth_name(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = usize::try_from(self.remaining()).unwrap_or(usize::MAX);
+
+        (remaining, usize::try_from(self.remaining()).ok())
+    }
+}
+
+impl FusedIterator for UniqueNameGenerator<'_> {}
+
+/// The SplitMix64 finalizer, used as the Feistel round function.
+const fn mix(mut value: u64) -> u64 {
+    value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    value ^ (value >> 31)
+}
+
+#[cfg(test)]
+mod test {
+    use super::UniqueNameGenerator;
+    use crate::{DEFAULT_GOOFY_ANIMALS, GoofyAnimals};
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn exhausts_tiny_space() {
+        let animals = GoofyAnimals::new(&["cat", "dog"], &["big", "red", "shy"]);
+        let mut names = UniqueNameGenerator::new(animals, 42);
+
+        let mut seen: ::alloc::vec::Vec<_> = names.by_ref().collect();
+        assert_eq!(seen.len(), 12);
+        assert!(names.is_exhausted());
+        assert_eq!(names.next(), None);
+
+        seen.sort();
+        seen.dedup();
+        assert_eq!(seen.len(), 12);
+    }
+
+    #[test]
+    fn smallest_space() {
+        let animals = GoofyAnimals::new(&["cat"], &["big", "red"]);
+        let names: ::alloc::vec::Vec<_> = UniqueNameGenerator::new(animals, 7)
+            .map(|name| ::alloc::format!("{name}"))
+            .collect();
+
+        assert_eq!(names.len(), 2);
+        assert!(names.contains(&"big-red-cat".into()));
+        assert!(names.contains(&"red-big-cat".into()));
+    }
+
+    #[test]
+    fn no_repeats() {
+        let names = UniqueNameGenerator::new(DEFAULT_GOOFY_ANIMALS, 0x1337);
+
+        let seen: std::collections::HashSet<_> = names.take(100_000).collect();
+        assert_eq!(seen.len(), 100_000);
+    }
+
+    #[test]
+    fn resume() {
+        let mut names = UniqueNameGenerator::new(DEFAULT_GOOFY_ANIMALS, 0x1337);
+        names.nth(41);
+        assert_eq!(names.position(), 42);
+
+        let mut resumed = UniqueNameGenerator::resume(DEFAULT_GOOFY_ANIMALS, 0x1337, 42);
+        for _ in 0..100 {
+            assert_eq!(names.next(), resumed.next());
+        }
+
+        let mut other = UniqueNameGenerator::new(DEFAULT_GOOFY_ANIMALS, 0x1338);
+        names = UniqueNameGenerator::new(DEFAULT_GOOFY_ANIMALS, 0x1337);
+        assert_ne!(names.next(), other.next());
+    }
+
+    #[test]
+    fn remaining() {
+        let names = UniqueNameGenerator::resume(
+            DEFAULT_GOOFY_ANIMALS,
+            0,
+            DEFAULT_GOOFY_ANIMALS.combinations() - 1,
+        );
+
+        assert_eq!(names.remaining(), 1);
+        assert_eq!(names.count(), 1);
+    }
+}
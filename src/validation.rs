@@ -0,0 +1,720 @@
+use core::fmt::{Display, Formatter};
+
+use crate::{MAX_ADJECTIVES, WordList};
+
+/// Identifies one of the two word lists of a [`GoofyAnimals`](crate::GoofyAnimals) instance.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WordListKind {
+    /// The list of animals.
+    Animals,
+    /// The list of adjectives.
+    Adjectives,
+}
+
+/// The reason a pair of word lists was rejected by
+/// [`GoofyAnimals::try_new`](crate::GoofyAnimals::try_new).
+///
+/// Errors about a single word carry the list and the position of the offending word
+/// in it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WordListError {
+    /// More adjectives per name were requested than [`MAX_ADJECTIVES`].
+    TooManyAdjectivesPerName { adjective_count: usize },
+    /// The animals list is empty.
+    EmptyAnimals,
+    /// The adjectives list has fewer entries than adjectives per name.
+    TooFewAdjectives { required: usize, found: usize },
+    /// The last word of a list is empty, usually because of a trailing newline.
+    TrailingEmptyWord { list: WordListKind, index: usize },
+    /// A word other than the last one is empty.
+    EmptyWord { list: WordListKind, index: usize },
+    /// A word appears twice in the same list; `first` is the position of its first occurrence.
+    DuplicateWord {
+        list: WordListKind,
+        index: usize,
+        first: usize,
+    },
+    /// A word contains a carriage return, usually left over from a Windows checkout.
+    CarriageReturn { list: WordListKind, index: usize },
+    /// A word starts or ends with whitespace.
+    LeadingOrTrailingWhitespace { list: WordListKind, index: usize },
+    /// A word has whitespace other than single spaces between its parts.
+    Whitespace { list: WordListKind, index: usize },
+    /// A word contains a separator character, `-` or `_`.
+    Separator { list: WordListKind, index: usize },
+    /// A word contains an uppercase letter.
+    Uppercase { list: WordListKind, index: usize },
+    /// A word contains a control or other non-printable character.
+    NonPrintable { list: WordListKind, index: usize },
+}
+
+/// A problem with a single word, before it's attached to a list and position.
+enum WordProblem {
+    Empty,
+    CarriageReturn,
+    LeadingOrTrailingWhitespace,
+    Whitespace,
+    Separator,
+    Uppercase,
+    NonPrintable,
+}
+
+/// Checks word lists against the rules of [`GoofyAnimals::new`](crate::GoofyAnimals::new)
+/// and [`GoofyAnimals::try_new`](crate::GoofyAnimals::try_new) at runtime.
+///
+/// Duplicates are found on a sorted index of each list with the `alloc` feature,
+/// and by comparing every pair of words without it.
+pub(crate) fn validate(
+    animals: WordList<'_>,
+    adjectives: WordList<'_>,
+    adjective_count: usize,
+) -> Result<(), WordListError> {
+    check_sizes(animals, adjectives, adjective_count)?;
+    validate_list(animals, WordListKind::Animals)?;
+    validate_list(adjectives, WordListKind::Adjectives)
+}
+
+/// Checks word lists like [`validate`], in `const` context.
+pub(crate) const fn validate_const(
+    animals: WordList<'_>,
+    adjectives: WordList<'_>,
+    adjective_count: usize,
+) -> Result<(), WordListError> {
+    if let Err(error) = check_sizes(animals, adjectives, adjective_count) {
+        return Err(error);
+    }
+
+    match validate_list_const(animals, WordListKind::Animals) {
+        Ok(()) => validate_list_const(adjectives, WordListKind::Adjectives),
+        Err(error) => Err(error),
+    }
+}
+
+const fn check_sizes(
+    animals: WordList<'_>,
+    adjectives: WordList<'_>,
+    adjective_count: usize,
+) -> Result<(), WordListError> {
+    if adjective_count > MAX_ADJECTIVES {
+        return Err(WordListError::TooManyAdjectivesPerName { adjective_count });
+    }
+
+    if animals.is_empty() {
+        return Err(WordListError::EmptyAnimals);
+    }
+
+    if adjectives.len() < adjective_count {
+        return Err(WordListError::TooFewAdjectives {
+            required: adjective_count,
+            found: adjectives.len(),
+        });
+    }
+
+    Ok(())
+}
+
+fn validate_list(words: WordList<'_>, list: WordListKind) -> Result<(), WordListError> {
+    let duplicate = first_duplicate(words);
+
+    for index in 0..words.len() {
+        check_entry(words, index, list)?;
+
+        if let Some((duplicate, first)) = duplicate
+            && duplicate == index
+        {
+            return Err(WordListError::DuplicateWord { list, index, first });
+        }
+    }
+
+    Ok(())
+}
+
+const fn validate_list_const(words: WordList<'_>, list: WordListKind) -> Result<(), WordListError> {
+    let mut seen = [0; SEEN_SLOTS];
+
+    let mut index = 0;
+    while index < words.len() {
+        if let Err(error) = check_entry(words, index, list) {
+            return Err(error);
+        }
+
+        if let Some(first) = find_duplicate(words, index, &mut seen) {
+            return Err(WordListError::DuplicateWord { list, index, first });
+        }
+
+        index += 1;
+    }
+
+    Ok(())
+}
+
+/// Checks the word at `index` on its own, without looking for duplicates.
+const fn check_entry(
+    words: WordList<'_>,
+    index: usize,
+    list: WordListKind,
+) -> Result<(), WordListError> {
+    match check_word(words.word(index).as_bytes()) {
+        Ok(()) => Ok(()),
+        Err(WordProblem::Empty) if index + 1 == words.len() => {
+            Err(WordListError::TrailingEmptyWord { list, index })
+        }
+        Err(WordProblem::Empty) => Err(WordListError::EmptyWord { list, index }),
+        Err(WordProblem::CarriageReturn) => Err(WordListError::CarriageReturn { list, index }),
+        Err(WordProblem::LeadingOrTrailingWhitespace) => {
+            Err(WordListError::LeadingOrTrailingWhitespace { list, index })
+        }
+        Err(WordProblem::Whitespace) => Err(WordListError::Whitespace { list, index }),
+        Err(WordProblem::Separator) => Err(WordListError::Separator { list, index }),
+        Err(WordProblem::Uppercase) => Err(WordListError::Uppercase { list, index }),
+        Err(WordProblem::NonPrintable) => Err(WordListError::NonPrintable { list, index }),
+    }
+}
+
+/// Finds the first word that repeats an earlier one, returning its position and
+/// the position of the earlier word.
+#[cfg(feature = "alloc")]
+fn first_duplicate(words: WordList<'_>) -> Option<(usize, usize)> {
+    // The sort is stable, so equal words stay in the order of the list
+    let mut sorted: ::alloc::vec::Vec<usize> = (0..words.len()).collect();
+    sorted.sort_by_key(|&index| words.word(index));
+
+    sorted
+        .chunk_by(|&a, &b| words.word(a) == words.word(b))
+        .filter_map(|equal| Some((*equal.get(1)?, equal[0])))
+        .min()
+}
+
+/// Finds the first word that repeats an earlier one, returning its position and
+/// the position of the earlier word.
+#[cfg(not(feature = "alloc"))]
+fn first_duplicate(words: WordList<'_>) -> Option<(usize, usize)> {
+    (0..words.len()).find_map(|index| {
+        (0..index)
+            .find(|&first| words.word(first) == words.word(index))
+            .map(|first| (index, first))
+    })
+}
+
+/// Number of slots in the hash table used to find duplicate words in `const` context.
+const SEEN_SLOTS: usize = 8192;
+
+/// Looks for an earlier occurrence of `words[index]`, remembering the word for later
+/// calls.
+///
+/// Comparing every pair of words is too slow for `const` evaluation of lists with
+/// more than a few hundred words, so the words are kept in an open addressing hash
+/// table holding their positions plus one. Lists too long for the table to stay at
+/// most half full fall back to pairwise comparisons. The table is too large for the
+/// stack of small targets, so this is only used by [`validate_const`].
+const fn find_duplicate(
+    words: WordList<'_>,
+    index: usize,
+    seen: &mut [u16; SEEN_SLOTS],
+) -> Option<usize> {
+    let word = words.word(index).as_bytes();
+
+    if words.len() > SEEN_SLOTS / 2 {
+        let mut first = 0;
+        while first < index {
+            if bytes_equal(words.word(first).as_bytes(), word) {
+                return Some(first);
+            }
+
+            first += 1;
+        }
+
+        return None;
+    }
+
+    let mut slot = fnv1a(word) as usize % SEEN_SLOTS;
+    loop {
+        match seen[slot] {
+            0 => {
+                seen[slot] = index as u16 + 1;
+                return None;
+            }
+            entry => {
+                let first = entry as usize - 1;
+                if bytes_equal(words.word(first).as_bytes(), word) {
+                    return Some(first);
+                }
+            }
+        }
+
+        slot = (slot + 1) % SEEN_SLOTS;
+    }
+}
+
+/// The 32-bit FNV-1a hash.
+const fn fnv1a(bytes: &[u8]) -> u32 {
+    let mut hash: u32 = 0x811c_9dc5;
+
+    let mut index = 0;
+    while index < bytes.len() {
+        hash = (hash ^ bytes[index] as u32).wrapping_mul(0x0100_0193);
+        index += 1;
+    }
+
+    hash
+}
+
+const fn check_word(word: &[u8]) -> Result<(), WordProblem> {
+    if word.is_empty() {
+        return Err(WordProblem::Empty);
+    }
+
+    let mut offset = 0;
+    let mut previous_space = false;
+
+    while offset < word.len() {
+        let (c, len) = decode_utf8(word, offset);
+
+        if c == '\r' {
+            return Err(WordProblem::CarriageReturn);
+        } else if c.is_whitespace() && (offset == 0 || offset + len == word.len()) {
+            return Err(WordProblem::LeadingOrTrailingWhitespace);
+        } else if c.is_whitespace() && (c != ' ' || previous_space) {
+            // A single space may join the parts of a word like `polar bear`
+            return Err(WordProblem::Whitespace);
+        } else if c == '-' || c == '_' {
+            return Err(WordProblem::Separator);
+        } else if c.is_uppercase() {
+            return Err(WordProblem::Uppercase);
+        } else if !is_printable(c) {
+            return Err(WordProblem::NonPrintable);
+        }
+
+        previous_space = c == ' ';
+        offset += len;
+    }
+
+    Ok(())
+}
+
+/// Returns `false` for control characters and invisible formatting characters.
+const fn is_printable(c: char) -> bool {
+    !matches!(
+        c,
+        '\u{0}'..='\u{1f}'
+            | '\u{7f}'..='\u{9f}'
+            | '\u{ad}'
+            | '\u{200b}'..='\u{200f}'
+            | '\u{202a}'..='\u{202e}'
+            | '\u{2060}'..='\u{2064}'
+            | '\u{feff}'
+    )
+}
+
+/// Decodes the character starting at `offset` of valid UTF-8, returning it with its
+/// length in bytes.
+const fn decode_utf8(bytes: &[u8], offset: usize) -> (char, usize) {
+    let first = bytes[offset] as u32;
+
+    let (mut code, len) = match first {
+        0x00..=0x7f => return (first as u8 as char, 1),
+        0xc0..=0xdf => (first & 0x1f, 2),
+        0xe0..=0xef => (first & 0x0f, 3),
+        _ => (first & 0x07, 4),
+    };
+
+    let mut index = 1;
+    while index < len {
+        code = (code << 6) | (bytes[offset + index] as u32 & 0x3f);
+        index += 1;
+    }
+
+    match char::from_u32(code) {
+        Some(c) => (c, len),
+        None => (char::REPLACEMENT_CHARACTER, len),
+    }
+}
+
+const fn bytes_equal(one: &[u8], two: &[u8]) -> bool {
+    if one.len() != two.len() {
+        return false;
+    }
+
+    let mut index = 0;
+    while index < one.len() {
+        if one[index] != two[index] {
+            return false;
+        }
+
+        index += 1;
+    }
+
+    true
+}
+
+impl WordListError {
+    /// Returns the list holding the offending word, if the error is about a single word.
+    pub const fn list(&self) -> Option<WordListKind> {
+        match *self {
+            Self::TooManyAdjectivesPerName { .. }
+            | Self::EmptyAnimals
+            | Self::TooFewAdjectives { .. } => None,
+            Self::TrailingEmptyWord { list, .. }
+            | Self::EmptyWord { list, .. }
+            | Self::DuplicateWord { list, .. }
+            | Self::CarriageReturn { list, .. }
+            | Self::LeadingOrTrailingWhitespace { list, .. }
+            | Self::Whitespace { list, .. }
+            | Self::Separator { list, .. }
+            | Self::Uppercase { list, .. }
+            | Self::NonPrintable { list, .. } => Some(list),
+        }
+    }
+
+    /// Returns the position of the offending word in its list, if the error is about a
+    /// single word.
+    pub const fn index(&self) -> Option<usize> {
+        match *self {
+            Self::TooManyAdjectivesPerName { .. }
+            | Self::EmptyAnimals
+            | Self::TooFewAdjectives { .. } => None,
+            Self::TrailingEmptyWord { index, .. }
+            | Self::EmptyWord { index, .. }
+            | Self::DuplicateWord { index, .. }
+            | Self::CarriageReturn { index, .. }
+            | Self::LeadingOrTrailingWhitespace { index, .. }
+            | Self::Whitespace { index, .. }
+            | Self::Separator { index, .. }
+            | Self::Uppercase { index, .. }
+            | Self::NonPrintable { index, .. } => Some(index),
+        }
+    }
+
+    /// Returns a message for panicking in `const` context, where the error can't be
+    /// formatted.
+    pub(crate) const fn panic_message(&self) -> &'static str {
+        use WordListKind::{Adjectives, Animals};
+
+        match self {
+            Self::TooManyAdjectivesPerName { .. } => "too many adjectives per name",
+            Self::EmptyAnimals => "empty animals",
+            Self::TooFewAdjectives { .. } => "fewer adjectives than adjectives per name",
+            Self::TrailingEmptyWord { list: Animals, .. } => "trailing newline in animals",
+            Self::TrailingEmptyWord {
+                list: Adjectives, ..
+            } => "trailing newline in adjectives",
+            Self::EmptyWord { list: Animals, .. } => "empty word in animals",
+            Self::EmptyWord {
+                list: Adjectives, ..
+            } => "empty word in adjectives",
+            Self::DuplicateWord { list: Animals, .. } => "duplicate word in animals",
+            Self::DuplicateWord {
+                list: Adjectives, ..
+            } => "duplicate word in adjectives",
+            Self::CarriageReturn { list: Animals, .. } => "carriage return in animals",
+            Self::CarriageReturn {
+                list: Adjectives, ..
+            } => "carriage return in adjectives",
+            Self::LeadingOrTrailingWhitespace { list: Animals, .. } => {
+                "leading or trailing whitespace in animals"
+            }
+            Self::LeadingOrTrailingWhitespace {
+                list: Adjectives, ..
+            } => "leading or trailing whitespace in adjectives",
+            Self::Whitespace { list: Animals, .. } => "whitespace inside a word in animals",
+            Self::Whitespace {
+                list: Adjectives, ..
+            } => "whitespace inside a word in adjectives",
+            Self::Separator { list: Animals, .. } => "separator in animals",
+            Self::Separator {
+                list: Adjectives, ..
+            } => "separator in adjectives",
+            Self::Uppercase { list: Animals, .. } => "uppercase letter in animals",
+            Self::Uppercase {
+                list: Adjectives, ..
+            } => "uppercase letter in adjectives",
+            Self::NonPrintable { list: Animals, .. } => "non-printable character in animals",
+            Self::NonPrintable {
+                list: Adjectives, ..
+            } => "non-printable character in adjectives",
+        }
+    }
+}
+
+impl Display for WordListKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Animals => f.write_str("animals"),
+            Self::Adjectives => f.write_str("adjectives"),
+        }
+    }
+}
+
+impl Display for WordListError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TooManyAdjectivesPerName { adjective_count } => write!(
+                f,
+                "{adjective_count} adjectives per name, at most {MAX_ADJECTIVES} are supported"
+            ),
+            Self::EmptyAnimals => write!(f, "empty animals"),
+            Self::TooFewAdjectives { required, found } => {
+                write!(f, "{found} adjectives, but names need {required}")
+            }
+            Self::TrailingEmptyWord { list, index } => {
+                write!(f, "trailing empty word at {list} index {index}")
+            }
+            Self::EmptyWord { list, index } => write!(f, "empty word at {list} index {index}"),
+            Self::DuplicateWord { list, index, first } => write!(
+                f,
+                "duplicate word at {list} index {index}, first seen at index {first}"
+            ),
+            Self::CarriageReturn { list, index } => {
+                write!(f, "carriage return in word at {list} index {index}")
+            }
+            Self::LeadingOrTrailingWhitespace { list, index } => write!(
+                f,
+                "leading or trailing whitespace in word at {list} index {index}"
+            ),
+            Self::Whitespace { list, index } => {
+                write!(f, "unexpected whitespace in word at {list} index {index}")
+            }
+            Self::Separator { list, index } => {
+                write!(f, "separator in word at {list} index {index}")
+            }
+            Self::Uppercase { list, index } => {
+                write!(f, "uppercase letter in word at {list} index {index}")
+            }
+            Self::NonPrintable { list, index } => {
+                write!(f, "non-printable character in word at {list} index {index}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for WordListError {}
+
+#[cfg(test)]
+mod test {
+    use super::{WordListError, WordListKind, validate, validate_const};
+    use crate::{DEFAULT_GOOFY_ANIMALS, MAX_ADJECTIVES, WordList};
+
+    use pretty_assertions::assert_eq;
+
+    const ADJECTIVES: &[&str] = &["big", "red"];
+
+    fn check(
+        animals: &[&str],
+        adjectives: &[&str],
+        adjective_count: usize,
+    ) -> Result<(), WordListError> {
+        validate(
+            WordList::from_slice(animals),
+            WordList::from_slice(adjectives),
+            adjective_count,
+        )
+    }
+
+    fn animals(animals: &[&str]) -> Result<(), WordListError> {
+        check(animals, ADJECTIVES, 2)
+    }
+
+    #[test]
+    fn default_lists() {
+        assert_eq!(
+            validate(
+                DEFAULT_GOOFY_ANIMALS.animal_list(),
+                DEFAULT_GOOFY_ANIMALS.adjective_list(),
+                MAX_ADJECTIVES,
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn long_lists() {
+        for total in [4000, 5000, 70_000] {
+            let owned: ::alloc::vec::Vec<_> =
+                (0..total).map(|i| ::alloc::format!("w{i}")).collect();
+            let mut words: ::alloc::vec::Vec<&str> = owned.iter().map(|w| w.as_str()).collect();
+            assert_eq!(animals(&words), Ok(()));
+
+            words.push("w1234");
+            let expected = Err(WordListError::DuplicateWord {
+                list: WordListKind::Animals,
+                index: total,
+                first: 1234,
+            });
+            assert_eq!(animals(&words), expected);
+
+            // Pairwise comparisons of the const checks are too slow for the longest list
+            if total < 10_000 {
+                let words = WordList::from_slice(&words);
+                assert_eq!(
+                    validate_const(words, WordList::from_slice(ADJECTIVES), 2),
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn first_error() {
+        // The runtime and const checks report the first problem in list order
+        for (words, expected) in [
+            (
+                &["cat", "Owl", "cat"][..],
+                WordListError::Uppercase {
+                    list: WordListKind::Animals,
+                    index: 1,
+                },
+            ),
+            (
+                &["cat", "dog", "cat", "Owl", "dog"][..],
+                WordListError::DuplicateWord {
+                    list: WordListKind::Animals,
+                    index: 2,
+                    first: 0,
+                },
+            ),
+            (
+                &["dog", "cat", "cat", "dog", "dog"][..],
+                WordListError::DuplicateWord {
+                    list: WordListKind::Animals,
+                    index: 2,
+                    first: 1,
+                },
+            ),
+        ] {
+            assert_eq!(animals(words), Err(expected));
+            assert_eq!(
+                validate_const(
+                    WordList::from_slice(words),
+                    WordList::from_slice(ADJECTIVES),
+                    2
+                ),
+                Err(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn list_sizes() {
+        assert_eq!(
+            check(&["cat"], ADJECTIVES, MAX_ADJECTIVES + 1),
+            Err(WordListError::TooManyAdjectivesPerName {
+                adjective_count: MAX_ADJECTIVES + 1
+            })
+        );
+        assert_eq!(check(&[], ADJECTIVES, 2), Err(WordListError::EmptyAnimals));
+        assert_eq!(
+            check(&["cat"], ADJECTIVES, 3),
+            Err(WordListError::TooFewAdjectives {
+                required: 3,
+                found: 2
+            })
+        );
+        assert_eq!(check(&["cat"], &[], 0), Ok(()));
+    }
+
+    #[test]
+    fn words() {
+        const LIST: WordListKind = WordListKind::Animals;
+
+        assert_eq!(animals(&["cat", "polar bear", "élan"]), Ok(()));
+        assert_eq!(
+            animals(&["cat", ""]),
+            Err(WordListError::TrailingEmptyWord {
+                list: LIST,
+                index: 1
+            })
+        );
+        assert_eq!(
+            animals(&["", "cat"]),
+            Err(WordListError::EmptyWord {
+                list: LIST,
+                index: 0
+            })
+        );
+        assert_eq!(
+            animals(&["cat", "dog", "cat"]),
+            Err(WordListError::DuplicateWord {
+                list: LIST,
+                index: 2,
+                first: 0
+            })
+        );
+
+        assert_eq!(
+            animals(&["dog", "cat\r"]),
+            Err(WordListError::CarriageReturn {
+                list: LIST,
+                index: 1
+            })
+        );
+
+        for word in [" cat", "cat ", "\tcat", "cat\n", "\u{a0}cat"] {
+            assert_eq!(
+                animals(&["dog", word]),
+                Err(WordListError::LeadingOrTrailingWhitespace {
+                    list: LIST,
+                    index: 1
+                }),
+                "{word:?}"
+            );
+        }
+
+        for word in ["polar  bear", "polar\tbear", "polar\u{a0}bear"] {
+            assert_eq!(
+                animals(&["dog", word]),
+                Err(WordListError::Whitespace {
+                    list: LIST,
+                    index: 1
+                }),
+                "{word:?}"
+            );
+        }
+
+        for word in ["Cat", "polar Bear", "ÉLAN"] {
+            assert_eq!(
+                animals(&[word]),
+                Err(WordListError::Uppercase {
+                    list: LIST,
+                    index: 0
+                }),
+                "{word:?}"
+            );
+        }
+
+        for word in ["polar-bear", "polar_bear"] {
+            assert_eq!(
+                animals(&[word]),
+                Err(WordListError::Separator {
+                    list: LIST,
+                    index: 0
+                })
+            );
+        }
+
+        for word in [
+            "cat\0",
+            "c\u{7f}at",
+            "c\u{9f}at",
+            "c\u{200b}at",
+            "\u{feff}cat",
+        ] {
+            assert_eq!(
+                animals(&[word]),
+                Err(WordListError::NonPrintable {
+                    list: LIST,
+                    index: 0
+                }),
+                "{word:?}"
+            );
+        }
+
+        assert_eq!(
+            check(&["cat"], &["big", "big"], 2),
+            Err(WordListError::DuplicateWord {
+                list: WordListKind::Adjectives,
+                index: 1,
+                first: 0
+            })
+        );
+    }
+}
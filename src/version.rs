@@ -0,0 +1,143 @@
+use crate::GoofyAnimals;
+
+/// A frozen snapshot of the built-in word lists and of how words are picked from them.
+///
+/// [`DEFAULT_GOOFY_ANIMALS`](crate::DEFAULT_GOOFY_ANIMALS) follows the latest word
+/// lists, so improving them changes the names generated from a given seed. A
+/// versioned snapshot never changes once released: pinning a version keeps the
+/// words, their order and the sampling method, and so the names generated from a
+/// given seed, the names of [`GoofyAnimals::nth_name`](crate::GoofyAnimals::nth_name)
+/// and the positions of [`GoofyAnimals::index_of`](crate::GoofyAnimals::index_of),
+/// identical across crate releases. New snapshots are added as new variants.
+///
+/// # Examples
+///
+/// ```rust
+/// use rand::SeedableRng;
+/// use rand_chacha::ChaCha20Rng;
+/// use goofy_animals::{GoofyAnimals, WordListVersion};
+///
+/// const ANIMALS: GoofyAnimals<'static> = WordListVersion::V1.goofy_animals();
+///
+/// let mut rng = ChaCha20Rng::seed_from_u64(0x1337);
+/// assert_eq!(ANIMALS.generate_name(&mut rng), "dismal-outlying-moth");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum WordListVersion {
+    /// The original word lists: 355 animals and 1300 adjectives.
+    ///
+    /// Adjectives are drawn from the whole list until they are all distinct, as the
+    /// first releases did.
+    V1,
+    /// The word lists of [`V1`](Self::V1), with each word drawn by a single
+    /// [`sample_below`](crate::sample_below) call from the words still available.
+    V2,
+}
+
+impl WordListVersion {
+    /// The most recent snapshot, which matches the current built-in word lists.
+    pub const LATEST: Self = Self::V2;
+
+    /// Returns a `GoofyAnimals` instance using the word lists of this version.
+    ///
+    /// Names generated by the instance have two adjectives, like those of
+    /// [`DEFAULT_GOOFY_ANIMALS`](crate::DEFAULT_GOOFY_ANIMALS).
+    pub const fn goofy_animals(self) -> GoofyAnimals<'static> {
+        let animals = match self {
+            Self::V1 | Self::V2 => builtin_goofy_animals!(
+                animals = "data/v1/en_animals.txt",
+                adjectives = "data/v1/en_adjectives.txt",
+            ),
+        };
+
+        animals.with_sampling(self)
+    }
+}
+
+impl From<WordListVersion> for GoofyAnimals<'static> {
+    fn from(version: WordListVersion) -> Self {
+        version.goofy_animals()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::WordListVersion;
+    use crate::DEFAULT_GOOFY_ANIMALS;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn latest() {
+        let latest = WordListVersion::LATEST.goofy_animals();
+
+        assert_eq!(latest.animal_list(), DEFAULT_GOOFY_ANIMALS.animal_list());
+        assert_eq!(
+            latest.adjective_list(),
+            DEFAULT_GOOFY_ANIMALS.adjective_list()
+        );
+    }
+
+    #[test]
+    fn v1() {
+        use rand::{Rng, SeedableRng};
+        use rand_chacha::ChaCha20Rng;
+
+        use crate::NameStyle;
+
+        let animals = WordListVersion::V1.goofy_animals();
+        assert_eq!(animals.animal_list().len(), 355);
+        assert_eq!(animals.adjective_list().len(), 1300);
+        assert_eq!(animals.animal_list().get(246), Some("polar bear"));
+
+        let rng = ChaCha20Rng::seed_from_u64(0x1337);
+        let names: ::alloc::vec::Vec<_> = rng
+            .sample_iter(animals.styled(NameStyle::Kebab))
+            .take(4)
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "dismal-outlying-moth",
+                "healthy-yellowish-firefly",
+                "flat-faint-squirrel",
+                "glorious-educated-louse",
+            ]
+        );
+        assert_eq!(
+            animals.nth_name(123_456_789).unwrap().to_string(),
+            "disloyal-sad-muskox"
+        );
+    }
+
+    #[test]
+    fn v2() {
+        use rand::{Rng, SeedableRng};
+        use rand_chacha::ChaCha20Rng;
+
+        use crate::NameStyle;
+
+        let animals = WordListVersion::V2.goofy_animals();
+        assert_eq!(animals.sampling(), WordListVersion::V2);
+        assert_eq!(
+            animals.animal_list(),
+            WordListVersion::V1.goofy_animals().animal_list()
+        );
+
+        let rng = ChaCha20Rng::seed_from_u64(0x1337);
+        let names: ::alloc::vec::Vec<_> = rng
+            .sample_iter(animals.styled(NameStyle::Kebab))
+            .take(4)
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "outlying-haunting-firefly",
+                "faint-glossy-louse",
+                "glittering-relieved-booby",
+                "thankful-black-marten",
+            ]
+        );
+    }
+}
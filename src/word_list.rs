@@ -0,0 +1,250 @@
+use core::fmt::{Debug, Formatter};
+use core::iter::FusedIterator;
+use core::ops::{Index, Range};
+
+#[cfg(feature = "alloc")]
+use ::alloc::string::String;
+
+/// A read-only list of words, as used by [`GoofyAnimals`](crate::GoofyAnimals) for
+/// its animals and adjectives.
+///
+/// A `WordList` is a cheap, copyable view of words stored elsewhere: a slice of
+/// string slices, as built at compile time, the owned strings of a `GoofyAnimalsBuf`,
+/// or with the `packed-words` feature the compact storage of the built-in word lists.
+///
+/// # Examples
+///
+/// ```rust
+/// use goofy_animals::DEFAULT_GOOFY_ANIMALS;
+///
+/// let animals = DEFAULT_GOOFY_ANIMALS.animal_list();
+/// assert_eq!(animals.len(), 355);
+/// assert_eq!(&animals[0], "aardvark");
+/// assert_eq!(animals.iter().last(), Some("zebra"));
+/// ```
+#[derive(Clone, Copy)]
+pub struct WordList<'a> {
+    words: Words<'a>,
+}
+
+#[derive(Clone, Copy)]
+enum Words<'a> {
+    Borrowed(&'a [&'a str]),
+    #[cfg(feature = "alloc")]
+    Owned(&'a [String]),
+    /// Words separated by newlines in `text`, with `offsets` holding the start of
+    /// every word followed by the length of `text` plus one.
+    #[cfg(feature = "packed-words")]
+    Packed {
+        text: &'a str,
+        offsets: &'a [u16],
+    },
+}
+
+impl<'a> WordList<'a> {
+    /// Creates a word list borrowing a slice of string slices.
+    pub const fn from_slice(words: &'a [&'a str]) -> Self {
+        Self {
+            words: Words::Borrowed(words),
+        }
+    }
+
+    /// Creates a word list borrowing a slice of owned strings.
+    ///
+    /// # Feature Flag
+    ///
+    /// This function is only available when the `alloc` feature is enabled.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub const fn from_strings(words: &'a [String]) -> Self {
+        Self {
+            words: Words::Owned(words),
+        }
+    }
+
+    /// Creates a word list from newline-separated words and the table of their
+    /// offsets, as built for the built-in word lists with the `packed-words` feature.
+    #[cfg(feature = "packed-words")]
+    pub(crate) const fn from_packed(text: &'a str, offsets: &'a [u16]) -> Self {
+        Self {
+            words: Words::Packed { text, offsets },
+        }
+    }
+
+    /// Returns the number of words in the list.
+    pub const fn len(&self) -> usize {
+        match self.words {
+            Words::Borrowed(words) => words.len(),
+            #[cfg(feature = "alloc")]
+            Words::Owned(words) => words.len(),
+            #[cfg(feature = "packed-words")]
+            Words::Packed { offsets, .. } => offsets.len() - 1,
+        }
+    }
+
+    /// Returns `true` if the list has no words.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the word at `index`, or `None` if the index is out of range.
+    pub const fn get(&self, index: usize) -> Option<&'a str> {
+        if index < self.len() {
+            Some(self.word(index))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the underlying slice, if the list borrows a slice of string slices.
+    ///
+    /// This is `None` for owned word lists, and for the built-in word lists when
+    /// the `packed-words` feature is enabled.
+    pub const fn as_slice(&self) -> Option<&'a [&'a str]> {
+        match self.words {
+            Words::Borrowed(words) => Some(words),
+            #[cfg(feature = "alloc")]
+            Words::Owned(_) => None,
+            #[cfg(feature = "packed-words")]
+            Words::Packed { .. } => None,
+        }
+    }
+
+    /// Returns an iterator over the words of the list.
+    pub const fn iter(&self) -> WordListIter<'a> {
+        WordListIter {
+            list: *self,
+            range: 0..self.len(),
+        }
+    }
+
+    /// Returns the word at `index`, panicking if the index is out of range.
+    pub(crate) const fn word(&self, index: usize) -> &'a str {
+        match self.words {
+            Words::Borrowed(words) => words[index],
+            #[cfg(feature = "alloc")]
+            Words::Owned(words) => words[index].as_str(),
+            #[cfg(feature = "packed-words")]
+            Words::Packed { text, offsets } => {
+                let start = offsets[index] as usize;
+                let end = offsets[index + 1] as usize - 1;
+
+                text.split_at(start).1.split_at(end - start).0
+            }
+        }
+    }
+}
+
+impl Index<usize> for WordList<'_> {
+    type Output = str;
+
+    fn index(&self, index: usize) -> &str {
+        self.word(index)
+    }
+}
+
+impl<'a> From<&'a [&'a str]> for WordList<'a> {
+    fn from(words: &'a [&'a str]) -> Self {
+        Self::from_slice(words)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> From<&'a [String]> for WordList<'a> {
+    fn from(words: &'a [String]) -> Self {
+        Self::from_strings(words)
+    }
+}
+
+impl<'a> IntoIterator for WordList<'a> {
+    type Item = &'a str;
+    type IntoIter = WordListIter<'a>;
+
+    fn into_iter(self) -> WordListIter<'a> {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &WordList<'a> {
+    type Item = &'a str;
+    type IntoIter = WordListIter<'a>;
+
+    fn into_iter(self) -> WordListIter<'a> {
+        self.iter()
+    }
+}
+
+impl PartialEq for WordList<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl Eq for WordList<'_> {}
+
+impl Debug for WordList<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// An iterator over the words of a [`WordList`].
+///
+/// Created by [`WordList::iter`].
+#[derive(Clone, Debug)]
+pub struct WordListIter<'a> {
+    list: WordList<'a>,
+    range: Range<usize>,
+}
+
+impl<'a> Iterator for WordListIter<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        self.range.next().map(|index| self.list.word(index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<&'a str> {
+        self.range.nth(n).map(|index| self.list.word(index))
+    }
+}
+
+impl DoubleEndedIterator for WordListIter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|index| self.list.word(index))
+    }
+}
+
+impl ExactSizeIterator for WordListIter<'_> {}
+
+impl FusedIterator for WordListIter<'_> {}
+
+#[cfg(test)]
+mod test {
+    use super::WordList;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn borrowed_and_owned() {
+        let owned: ::alloc::vec::Vec<_> = ["moth", "polar bear"].map(Into::into).to_vec();
+        let owned = WordList::from_strings(&owned);
+        let borrowed = WordList::from_slice(&["moth", "polar bear"]);
+
+        assert_eq!(owned, borrowed);
+        assert_eq!(owned.len(), 2);
+        assert_eq!(owned.get(1), Some("polar bear"));
+        assert_eq!(owned.get(2), None);
+        assert_eq!(&owned[0], "moth");
+        assert_eq!(owned.as_slice(), None);
+        assert_eq!(borrowed.as_slice(), Some(&["moth", "polar bear"][..]));
+        assert_eq!(
+            owned.iter().rev().collect::<::alloc::vec::Vec<_>>(),
+            ["polar bear", "moth"]
+        );
+    }
+}